@@ -1,3 +1,3 @@
 fn main() {
-    multiversx_sc_meta::cli_main::<esdt_system_sc_mock::AbiProvider>();
+    multiversx_sc_meta_lib::cli_main::<esdt_system_sc_mock::AbiProvider>();
 }
@@ -0,0 +1,67 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+/// Keeps the royalties of each creator per payment token, the way the marketplace does
+#[multiversx_sc::contract]
+pub trait NftMarketplaceMock {
+    #[init]
+    fn init(&self) {}
+
+    #[payable("*")]
+    #[endpoint(depositRoyalties)]
+    fn deposit_royalties(&self, creator: ManagedAddress) {
+        let payment = self.call_value().egld_or_single_esdt();
+        self.claimable_amount(&creator, &payment.token_identifier, payment.token_nonce)
+            .update(|amount| *amount += payment.amount);
+    }
+
+    #[endpoint(claimTokens)]
+    fn claim_tokens(
+        &self,
+        claim_destination: ManagedAddress,
+        token_nonce_pairs: MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, u64>>,
+    ) -> MultiValue2<BigUint, ManagedVec<EsdtTokenPayment>> {
+        let caller = self.blockchain().get_caller();
+        let mut egld_amount = BigUint::zero();
+        let mut other_payments = ManagedVec::new();
+        for pair in token_nonce_pairs {
+            let (token_id, token_nonce) = pair.into_tuple();
+            let amount = self
+                .claimable_amount(&caller, &token_id, token_nonce)
+                .take();
+            if amount == 0 {
+                continue;
+            }
+
+            if token_id.is_egld() {
+                egld_amount += amount;
+            } else {
+                other_payments.push(EsdtTokenPayment::new(
+                    token_id.unwrap_esdt(),
+                    token_nonce,
+                    amount,
+                ));
+            }
+        }
+
+        if egld_amount > 0 {
+            self.send().direct_egld(&claim_destination, &egld_amount);
+        }
+        if !other_payments.is_empty() {
+            self.send()
+                .direct_multi(&claim_destination, &other_payments);
+        }
+
+        (egld_amount, other_payments).into()
+    }
+
+    #[view(getClaimableAmount)]
+    #[storage_mapper("claimableAmount")]
+    fn claimable_amount(
+        &self,
+        address: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -7,6 +7,8 @@ pub struct CreateNftMinterEvent<M: ManagedTypeApi> {
     royalties_claim_address: ManagedAddress<M>,
     mint_payments_claim_address: ManagedAddress<M>,
     max_nfts_per_transaction: usize,
+    platform_fee_address: ManagedAddress<M>,
+    platform_fee: u32,
     nft_minter_address: ManagedAddress<M>,
 }
 
@@ -18,17 +20,23 @@ pub trait EventsModule {
         royalties_claim_address: ManagedAddress,
         mint_payments_claim_address: ManagedAddress,
         max_nfts_per_transaction: usize,
+        platform_fee_address: ManagedAddress,
+        platform_fee: u32,
         nft_minter_address: ManagedAddress,
     ) {
         self.create_nft_minter_event(
             caller,
             self.blockchain().get_block_epoch(),
             self.blockchain().get_block_nonce(),
-            self.blockchain().get_block_timestamp(),
+            self.blockchain()
+                .get_block_timestamp_seconds()
+                .as_u64_seconds(),
             CreateNftMinterEvent {
                 royalties_claim_address,
                 mint_payments_claim_address,
                 max_nfts_per_transaction,
+                platform_fee_address,
+                platform_fee,
                 nft_minter_address,
             },
         )
@@ -40,17 +48,23 @@ pub trait EventsModule {
         royalties_claim_address: ManagedAddress,
         mint_payments_claim_address: ManagedAddress,
         max_nfts_per_transaction: usize,
+        platform_fee_address: ManagedAddress,
+        platform_fee: u32,
         nft_minter_address: ManagedAddress,
     ) {
         self.upgrade_nft_minter_event(
             caller,
             self.blockchain().get_block_epoch(),
             self.blockchain().get_block_nonce(),
-            self.blockchain().get_block_timestamp(),
+            self.blockchain()
+                .get_block_timestamp_seconds()
+                .as_u64_seconds(),
             CreateNftMinterEvent {
                 royalties_claim_address,
                 mint_payments_claim_address,
                 max_nfts_per_transaction,
+                platform_fee_address,
+                platform_fee,
                 nft_minter_address,
             },
         )
@@ -9,6 +9,8 @@ pub trait FactoryModule {
         royalties_claim_address: ManagedAddress,
         mint_payments_claim_address: ManagedAddress,
         max_nfts_per_transaction: usize,
+        platform_fee_address: ManagedAddress,
+        platform_fee: u32,
         admin: ManagedAddress,
     ) -> ManagedAddress {
         require!(
@@ -22,6 +24,8 @@ pub trait FactoryModule {
                 royalties_claim_address,
                 mint_payments_claim_address,
                 max_nfts_per_transaction,
+                platform_fee_address,
+                platform_fee,
                 OptionalValue::Some(admin.clone()),
             )
             .code_metadata(
@@ -44,6 +48,8 @@ pub trait FactoryModule {
         royalties_claim_address: ManagedAddress,
         mint_payments_claim_address: ManagedAddress,
         max_nfts_per_transaction: usize,
+        platform_fee_address: ManagedAddress,
+        platform_fee: u32,
     ) {
         self.tx()
             .to(nft_minter_address)
@@ -52,6 +58,8 @@ pub trait FactoryModule {
                 &royalties_claim_address,
                 &mint_payments_claim_address,
                 &max_nfts_per_transaction,
+                &platform_fee_address,
+                &platform_fee,
                 OptionalValue::None::<ManagedAddress>,
             )
             .code_metadata(
@@ -76,6 +84,24 @@ pub trait FactoryModule {
     #[storage_mapper("nftMinterTemplateAddress")]
     fn nft_minter_template_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Defaults to the deployer's owner
+    fn get_platform_fee_address(&self) -> ManagedAddress {
+        let mapper = self.platform_fee_address();
+        if mapper.is_empty() {
+            self.blockchain().get_owner_address()
+        } else {
+            mapper.get()
+        }
+    }
+
+    #[view(getPlatformFeeAddress)]
+    #[storage_mapper("platformFeeAddress")]
+    fn platform_fee_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPlatformFee)]
+    #[storage_mapper("platformFee")]
+    fn platform_fee(&self) -> SingleValueMapper<u32>;
+
     #[view(getNftMinterCreationEnabled)]
     #[storage_mapper("nftMinterCreationEnabled")]
     fn nft_minter_creation_enabled(&self) -> SingleValueMapper<bool>;
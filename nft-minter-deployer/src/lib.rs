@@ -34,10 +34,14 @@ pub trait NftMinterDeployer: factory::FactoryModule + events::EventsModule {
             );
         }
 
+        let platform_fee_address = self.get_platform_fee_address();
+        let platform_fee = self.platform_fee().get();
         let nft_minter_address = self.create_nft_minter(
             royalties_claim_address.clone(),
             mint_payments_claim_address.clone(),
             max_nfts_per_transaction,
+            platform_fee_address.clone(),
+            platform_fee,
             caller.clone(),
         );
 
@@ -46,6 +50,8 @@ pub trait NftMinterDeployer: factory::FactoryModule + events::EventsModule {
             royalties_claim_address,
             mint_payments_claim_address,
             max_nfts_per_transaction,
+            platform_fee_address,
+            platform_fee,
             nft_minter_address.clone(),
         );
 
@@ -60,6 +66,8 @@ pub trait NftMinterDeployer: factory::FactoryModule + events::EventsModule {
         royalties_claim_address: ManagedAddress,
         mint_payments_claim_address: ManagedAddress,
         max_nfts_per_transaction: usize,
+        platform_fee_address: ManagedAddress,
+        platform_fee: u32,
     ) {
         require!(
             self.all_nft_minter_contracts()
@@ -72,6 +80,8 @@ pub trait NftMinterDeployer: factory::FactoryModule + events::EventsModule {
             royalties_claim_address.clone(),
             mint_payments_claim_address.clone(),
             max_nfts_per_transaction,
+            platform_fee_address.clone(),
+            platform_fee,
         );
 
         self.emit_upgrade_nft_minter_event(
@@ -79,6 +89,8 @@ pub trait NftMinterDeployer: factory::FactoryModule + events::EventsModule {
             royalties_claim_address,
             mint_payments_claim_address,
             max_nfts_per_transaction,
+            platform_fee_address,
+            platform_fee,
             nft_minter_address,
         );
     }
@@ -160,6 +172,20 @@ pub trait NftMinterDeployer: factory::FactoryModule + events::EventsModule {
         self.nft_minter_template_address().set(&address);
     }
 
+    /// Applied to NFT minters created from now on.
+    /// Existing minters keep their fee until they are upgraded.
+    #[only_owner]
+    #[endpoint(setPlatformFee)]
+    fn set_platform_fee(&self, platform_fee_address: ManagedAddress, platform_fee: u32) {
+        require!(
+            platform_fee <= nft_minter::brand_creation::ROYALTIES_MAX,
+            "Platform fee cannot be over 100%"
+        );
+
+        self.platform_fee_address().set(&platform_fee_address);
+        self.platform_fee().set(platform_fee);
+    }
+
     #[only_owner]
     #[endpoint(setNftMinterCreationEnabled)]
     fn set_nft_minter_creation_enabled(&self, enabled: bool) {
@@ -3,13 +3,16 @@ use multiversx_sc_modules::pause::PauseModule;
 use multiversx_sc_scenario::{
     managed_address, rust_biguint, testing_framework::BlockchainStateWrapper,
 };
-use nft_minter::{admin_whitelist::AdminWhitelistModule, NftMinter};
+use nft_minter::{
+    admin_whitelist::AdminWhitelistModule, platform_fee::PlatformFeeModule, NftMinter,
+};
 use nft_minter_deployer::{factory::FactoryModule, NftMinterDeployer};
 
 pub const NFT_MINTER_WASM_PATH: &str = "nft-minter/output/nft-minter.wasm";
 pub const NFT_MINTER_DEPLOYER_WASM_PATH: &str =
     "nft-minter-deployer/output/nft-minter-deployer.wasm";
 pub const MAX_NFTS_PER_TX: usize = 2;
+pub const PLATFORM_FEE: u32 = 500;
 
 #[test]
 fn test_nft_minter_deployer() {
@@ -39,6 +42,8 @@ fn test_nft_minter_deployer() {
                 managed_address!(&user),
                 managed_address!(&user),
                 MAX_NFTS_PER_TX,
+                managed_address!(&owner),
+                0,
                 OptionalValue::None,
             );
         })
@@ -55,6 +60,7 @@ fn test_nft_minter_deployer() {
                 nft_minter_template_wrapper.address_ref()
             )));
             sc.set_nft_minter_creation_enabled(true);
+            sc.set_platform_fee(managed_address!(&owner), PLATFORM_FEE);
         })
         .assert_ok();
 
@@ -75,6 +81,13 @@ fn test_nft_minter_deployer() {
         })
         .assert_ok();
 
+    b_mock
+        .execute_query(&nft_minter_wrapper, |sc| {
+            assert_eq!(sc.platform_fee_address().get(), managed_address!(&owner));
+            assert_eq!(sc.platform_fee().get(), PLATFORM_FEE);
+        })
+        .assert_ok();
+
     // Test pause
     b_mock
         .execute_tx(&user, &nft_minter_deployer_wrapper, &rust_zero, |sc| {
@@ -127,6 +140,8 @@ fn test_nft_minter_deployer() {
                 managed_address!(&user),
                 managed_address!(&user),
                 MAX_NFTS_PER_TX,
+                managed_address!(&owner),
+                PLATFORM_FEE * 2,
             );
         })
         .assert_ok();
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           12
+// Endpoints:                           15
 // Async Callback (empty):               1
-// Total number of exported functions:  14
+// Total number of exported functions:  17
 
 #![no_std]
 
@@ -25,10 +25,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         addAdminToNftMinterContract => add_admin_to_nft_minter_contract
         removeAdminToNftMinterContract => remove_admin_to_nft_minter_contract
         setNftMinterTemplateAddress => set_nft_minter_template_address
+        setPlatformFee => set_platform_fee
         setNftMinterCreationEnabled => set_nft_minter_creation_enabled
         getUserNftMinterContracts => user_nft_minter_contracts
         getAllNftMinterContracts => all_nft_minter_contracts
         getNftMinterTemplateAddress => nft_minter_template_address
+        getPlatformFeeAddress => platform_fee_address
+        getPlatformFee => platform_fee
         getNftMinterCreationEnabled => nft_minter_creation_enabled
     )
 }
@@ -3,8 +3,9 @@ multiversx_sc::derive_imports!();
 
 use crate::{
     common_storage::{BrandId, BrandInfo, MintPrice, TimePeriod},
-    nft_attributes_builder::{CollectionHash, Tag},
+    nft_attributes_builder::{CollectionHash, Tag, Uri},
     nft_tier::{TierName, MAX_TIERS_PER_BRAND},
+    tier_pricing::PriceCurve,
 };
 
 const NFT_ISSUE_COST: u64 = 50_000_000_000_000_000; // 0.05 EGLD
@@ -19,8 +20,10 @@ pub static INVALID_TIER_ERR_MSG: &[u8] = b"Invalid tier";
 pub struct TempCallbackTierInfo<M: ManagedTypeApi> {
     pub tier: TierName<M>,
     pub total_nfts: usize,
+    pub reserved_nfts: usize,
     pub id_offset: usize,
     pub mint_price: MintPrice<M>,
+    pub opt_price_curve: Option<PriceCurve<M>>,
 }
 
 #[derive(TopEncode, TopDecode)]
@@ -30,8 +33,34 @@ pub struct TempCallbackStorageInfo<M: ManagedTypeApi> {
     pub tier_info_entries: ArrayVec<TempCallbackTierInfo<M>, MAX_TIERS_PER_BRAND>,
 }
 
-/// Tier name, number of NFTs, price
-pub type TierArgPair<M> = MultiValue3<TierName<M>, usize, BigUint<M>>;
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone, Copy)]
+pub enum BrandTokenKind {
+    Nft,
+    Edition,
+    DynamicNft,
+}
+
+impl BrandTokenKind {
+    pub fn esdt_token_type(&self) -> EsdtTokenType {
+        match self {
+            BrandTokenKind::Nft => EsdtTokenType::NonFungible,
+            BrandTokenKind::Edition => EsdtTokenType::SemiFungible,
+            BrandTokenKind::DynamicNft => EsdtTokenType::DynamicNFT,
+        }
+    }
+}
+
+/// Tier name, number of NFTs, price, optional price token (defaults to `mint_price_token_id`),
+/// optional bonding curve for the price, number of NFTs reserved for giveaways
+pub type TierArgPair<M> = MultiValue6<
+    TierName<M>,
+    usize,
+    BigUint<M>,
+    Option<EgldOrEsdtTokenIdentifier<M>>,
+    Option<PriceCurve<M>>,
+    usize,
+>;
 
 #[multiversx_sc::module]
 pub trait BrandCreationModule:
@@ -39,8 +68,12 @@ pub trait BrandCreationModule:
     + crate::common_storage::CommonStorageModule
     + crate::nft_attributes_builder::NftAttributesBuilderModule
     + crate::nft_tier::NftTierModule
+    + crate::tier_pricing::TierPricingModule
     + crate::events::EventsModule
 {
+    /// Dynamic NFTs can have their attributes and URIs updated after mint, see `NftUpdatesModule`.
+    /// Edition brands are SFT collections where each tier is a single edition,
+    /// i.e. one nonce with as many copies as the tier's number of NFTs.
     #[payable("EGLD")]
     #[endpoint(issueTokenForBrand)]
     fn issue_token_for_brand(
@@ -48,6 +81,7 @@ pub trait BrandCreationModule:
         collection_hash: CollectionHash<Self::Api>,
         brand_id: BrandId<Self::Api>,
         media_type: ManagedBuffer,
+        uri_base: Uri<Self::Api>,
         royalties: BigUint,
         mint_start_timestamp: u64,
         mint_end_timestamp: u64,
@@ -56,6 +90,7 @@ pub trait BrandCreationModule:
         token_ticker: ManagedBuffer,
         whitelist_expire_timestamp: u64,
         tags: ManagedVec<Tag<Self::Api>>,
+        token_kind: BrandTokenKind,
         tier_name_nr_nfts_pairs: MultiValueEncoded<TierArgPair<Self::Api>>,
     ) {
         self.require_caller_is_admin();
@@ -66,7 +101,7 @@ pub trait BrandCreationModule:
             INVALID_BRAND_ID_ERR_MSG
         );
 
-        let payment_amount = self.call_value().egld_value().clone_value();
+        let payment_amount = self.call_value().egld().clone_value();
         require!(
             payment_amount == NFT_ISSUE_COST,
             "Invalid payment amount. Issue costs exactly 0.05 EGLD"
@@ -76,6 +111,7 @@ pub trait BrandCreationModule:
             self.is_supported_media_type(&media_type),
             "Invalid media type"
         );
+        require!(self.is_valid_uri_base(&uri_base), "Invalid URI base");
         require!(royalties <= ROYALTIES_MAX, "Royalties cannot be over 100%");
         require!(mint_price_token_id.is_valid(), "Invalid price token");
 
@@ -87,6 +123,13 @@ pub trait BrandCreationModule:
         let is_new_brand = self.registered_brands().insert(brand_id.clone());
         require!(is_new_brand, "Brand already exists");
 
+        // the issue call ends the execution, and a failed issue clears the flags in the callback
+        match token_kind {
+            BrandTokenKind::Nft => {}
+            BrandTokenKind::Edition => self.is_edition_brand(&brand_id).set(true),
+            BrandTokenKind::DynamicNft => self.is_dynamic_brand(&brand_id).set(true),
+        }
+
         require!(
             mint_start_timestamp < mint_end_timestamp,
             "Invalid timestamps"
@@ -104,7 +147,20 @@ pub trait BrandCreationModule:
         let mut tiers_info = ArrayVec::new();
         let mut current_id_offset = 0;
         for pair in tier_name_nr_nfts_pairs {
-            let (tier, nr_nfts, price): (TierName<Self::Api>, usize, BigUint) = pair.into_tuple();
+            let (tier, nr_nfts, price, opt_price_token_id, opt_price_curve, reserved_nfts) =
+                pair.into_tuple();
+            require!(nr_nfts > 0, "Tier must have at least one NFT");
+            require!(reserved_nfts <= nr_nfts, "Reserved NFTs exceed tier supply");
+            let price_token_id = match opt_price_token_id {
+                Some(token_id) => {
+                    require!(token_id.is_valid(), "Invalid price token");
+                    token_id
+                }
+                None => mint_price_token_id.clone(),
+            };
+            if let Some(price_curve) = &opt_price_curve {
+                require!(price_curve.is_valid(), "Invalid price curve");
+            }
 
             let is_new_tier = tier_mapper.insert(tier.clone());
             require!(is_new_tier, "Duplicate tier name");
@@ -113,11 +169,13 @@ pub trait BrandCreationModule:
                 tiers_info.push_unchecked(TempCallbackTierInfo {
                     tier,
                     total_nfts: nr_nfts,
+                    reserved_nfts,
                     id_offset: current_id_offset,
                     mint_price: MintPrice {
-                        token_id: mint_price_token_id.clone(),
+                        token_id: price_token_id,
                         amount: price,
                     },
+                    opt_price_curve,
                 });
             }
             current_id_offset += nr_nfts;
@@ -127,6 +185,7 @@ pub trait BrandCreationModule:
             collection_hash: collection_hash.clone(),
             token_display_name: token_display_name.clone(),
             media_type,
+            uri_base,
             royalties,
             mint_period: TimePeriod {
                 start: mint_start_timestamp,
@@ -146,7 +205,7 @@ pub trait BrandCreationModule:
 
         require!(gas_before_issue > ISSUE_AND_CALLBACK_COST, "Not enough gas");
         self.nft_token(&brand_id).issue_and_set_all_roles(
-            EsdtTokenType::NonFungible,
+            token_kind.esdt_token_type(),
             payment_amount,
             token_display_name,
             token_ticker,
@@ -177,9 +236,21 @@ pub trait BrandCreationModule:
                         .set(tier_info.total_nfts);
                     self.nft_id_offset_for_tier(&brand_id, &tier_info.tier)
                         .set(tier_info.id_offset);
-
-                    self.price_for_tier(&brand_id, &tier_info.tier)
-                        .set(&tier_info.mint_price);
+                    self.reserved_nfts(&brand_id, &tier_info.tier)
+                        .set(tier_info.reserved_nfts);
+
+                    if let Some(price_curve) = tier_info.opt_price_curve {
+                        self.price_curve_for_tier(
+                            &brand_id,
+                            &tier_info.tier,
+                            &tier_info.mint_price.token_id,
+                        )
+                        .set(price_curve);
+                    }
+
+                    let _ = self
+                        .prices_for_tier(&brand_id, &tier_info.tier)
+                        .insert(tier_info.mint_price.token_id, tier_info.mint_price.amount);
                 }
 
                 if !cb_info.tags.is_empty() {
@@ -194,22 +265,29 @@ pub trait BrandCreationModule:
                     .registered_collection_hashes()
                     .swap_remove(&collection_hash);
                 self.nft_tiers_for_brand(&brand_id).clear();
+                self.is_edition_brand(&brand_id).clear();
+                self.is_dynamic_brand(&brand_id).clear();
             }
         }
 
         self.temporary_callback_storage(&brand_id).clear();
     }
 
+    /// Each user may mint up to `quota` NFTs before the whitelist expires
     #[endpoint(addToWhitelist)]
     fn add_to_whitelist(
         &self,
         brand_id: BrandId<Self::Api>,
-        users: MultiValueEncoded<ManagedAddress>,
+        user_quota_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
     ) {
         self.require_caller_is_admin();
 
         let mut mapper = self.mint_whitelist(&brand_id);
-        for user in users {
+        for pair in user_quota_pairs {
+            let (user, quota) = pair.into_tuple();
+            require!(quota > 0, "Invalid whitelist quota");
+
+            self.whitelist_quota(&brand_id, &user).set(quota);
             let _ = mapper.insert(user);
         }
     }
@@ -224,6 +302,7 @@ pub trait BrandCreationModule:
 
         let mut mapper = self.mint_whitelist(&brand_id);
         for user in users {
+            self.whitelist_quota(&brand_id, &user).clear();
             let _ = mapper.swap_remove(&user);
         }
     }
@@ -236,6 +315,61 @@ pub trait BrandCreationModule:
             .update(|info| info.whitelist_expire_timestamp = timestamp);
     }
 
+    #[endpoint(setMintPeriod)]
+    fn set_mint_period(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        mint_start_timestamp: u64,
+        mint_end_timestamp: u64,
+    ) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(
+            mint_start_timestamp < mint_end_timestamp,
+            "Invalid timestamps"
+        );
+
+        let mut brand_info = self.brand_info(&brand_id).get();
+        let current_timestamp = self
+            .blockchain()
+            .get_block_timestamp_seconds()
+            .as_u64_seconds();
+        if current_timestamp >= brand_info.mint_period.start {
+            require!(
+                mint_start_timestamp == brand_info.mint_period.start,
+                "May not change start after mint started"
+            );
+        }
+
+        brand_info.mint_period = TimePeriod {
+            start: mint_start_timestamp,
+            end: mint_end_timestamp,
+        };
+        self.brand_info(&brand_id).set(&brand_info);
+
+        self.mint_period_changed_event(&brand_id, &brand_info.mint_period);
+    }
+
+    #[endpoint(setUriBaseForBrand)]
+    fn set_uri_base_for_brand(&self, brand_id: BrandId<Self::Api>, uri_base: Uri<Self::Api>) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(self.is_valid_uri_base(&uri_base), "Invalid URI base");
+        require!(
+            !self.has_minted_nfts(&brand_id),
+            "May not change URI base after minting started"
+        );
+
+        self.brand_info(&brand_id)
+            .update(|info| info.uri_base = uri_base);
+    }
+
     #[storage_mapper("temporaryCallbackStorage")]
     fn temporary_callback_storage(
         &self,
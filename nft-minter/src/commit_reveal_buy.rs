@@ -0,0 +1,170 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc_modules::pause;
+
+use crate::{
+    common_storage::{BrandId, PaymentsVec},
+    nft_minting::{ConsumedAllowances, MintProof},
+    nft_tier::TierName,
+};
+
+pub const COMMIT_EXPIRY_BLOCKS: u64 = 100;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct PendingBuy<M: ManagedTypeApi> {
+    pub brand_id: BrandId<M>,
+    pub tier: TierName<M>,
+    pub nfts_to_buy: usize,
+    pub payment: EgldOrEsdtTokenPayment<M>,
+    pub commit_block: u64,
+    pub consumed_allowances: ConsumedAllowances,
+}
+
+/// Two-step alternative to `buyRandomNft`. The payment is locked and the NFTs are reserved
+/// on commit, while the IDs are drawn on reveal, from a later block's randomness.
+/// Buys that are not revealed in time can be refunded, which also gives back the buyer's quotas.
+#[multiversx_sc::module]
+pub trait CommitRevealBuyModule:
+    crate::nft_minting::NftMintingModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+    + crate::tier_pricing::TierPricingModule
+    + crate::mint_limits::MintLimitsModule
+    + crate::mint_phases::MintPhasesModule
+    + crate::merkle_whitelist::MerkleWhitelistModule
+    + crate::mint_signature::MintSignatureModule
+    + crate::delayed_reveal::DelayedRevealModule
+    + crate::nft_updates::NftUpdatesModule
+    + crate::royalties::RoyaltiesModule
+    + crate::refunds::RefundsModule
+    + crate::referrals::ReferralsModule
+    + crate::platform_fee::PlatformFeeModule
+    + crate::admin_whitelist::AdminWhitelistModule
+    + crate::nft_attributes_builder::NftAttributesBuilderModule
+    + crate::events::EventsModule
+    + pause::PauseModule
+{
+    #[payable("*")]
+    #[endpoint(commitBuy)]
+    #[allow_multiple_var_args]
+    fn commit_buy(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        opt_nfts_to_buy: OptionalValue<usize>,
+        opt_mint_proof: OptionalValue<MintProof<Self::Api>>,
+    ) {
+        self.require_not_paused();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.blockchain().is_smart_contract(&caller),
+            "Only user accounts are allowed to mint"
+        );
+
+        let pending_buy_mapper = self.pending_buy(&caller);
+        require!(pending_buy_mapper.is_empty(), "Pending buy already exists");
+
+        let nfts_to_buy = self.get_nfts_to_buy(opt_nfts_to_buy);
+        require!(nfts_to_buy > 0, "Must buy at least one NFT");
+
+        let (_, consumed_allowances) =
+            self.check_buy(&brand_id, &tier, nfts_to_buy, opt_mint_proof);
+        require!(
+            nfts_to_buy <= self.get_available_nfts(&brand_id, &tier),
+            "Not enough NFTs available"
+        );
+
+        self.committed_nfts(&brand_id, &tier)
+            .update(|committed| *committed += nfts_to_buy);
+        pending_buy_mapper.set(PendingBuy {
+            brand_id,
+            tier,
+            nfts_to_buy,
+            payment: self.call_value().egld_or_single_esdt(),
+            commit_block: self.blockchain().get_block_nonce(),
+            consumed_allowances,
+        });
+    }
+
+    #[endpoint(revealBuy)]
+    fn reveal_buy(&self) -> PaymentsVec<Self::Api> {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        let pending_buy_mapper = self.pending_buy(&caller);
+        require!(!pending_buy_mapper.is_empty(), "No pending buy");
+
+        let pending_buy = pending_buy_mapper.take();
+        let current_block = self.blockchain().get_block_nonce();
+        require!(
+            current_block > pending_buy.commit_block,
+            "May not reveal in the commit block"
+        );
+        require!(
+            current_block <= pending_buy.commit_block + COMMIT_EXPIRY_BLOCKS,
+            "Pending buy expired"
+        );
+
+        let brand_id = pending_buy.brand_id;
+        let tier = pending_buy.tier;
+        let nfts_to_buy = pending_buy.nfts_to_buy;
+        self.committed_nfts(&brand_id, &tier)
+            .update(|committed| *committed -= nfts_to_buy);
+
+        let brand_info = self.brand_info(&brand_id).get();
+        let output_payments =
+            self.mint_and_send_random_nft(&caller, &brand_id, &tier, &brand_info, nfts_to_buy);
+
+        self.distribute_mint_payment(
+            &brand_id,
+            &caller,
+            &output_payments,
+            pending_buy.payment,
+            None,
+        );
+
+        self.nft_bought_event(&caller, &brand_id, &tier, nfts_to_buy);
+
+        output_payments
+    }
+
+    #[endpoint(refundExpiredBuy)]
+    fn refund_expired_buy(&self) {
+        let caller = self.blockchain().get_caller();
+        let pending_buy_mapper = self.pending_buy(&caller);
+        require!(!pending_buy_mapper.is_empty(), "No pending buy");
+
+        let pending_buy = pending_buy_mapper.take();
+        let current_block = self.blockchain().get_block_nonce();
+        require!(
+            current_block > pending_buy.commit_block + COMMIT_EXPIRY_BLOCKS,
+            "Pending buy not expired yet"
+        );
+
+        self.committed_nfts(&pending_buy.brand_id, &pending_buy.tier)
+            .update(|committed| *committed -= pending_buy.nfts_to_buy);
+        self.restore_allowances(
+            &pending_buy.brand_id,
+            &pending_buy.tier,
+            &caller,
+            pending_buy.nfts_to_buy,
+            pending_buy.consumed_allowances,
+        );
+
+        let payment = pending_buy.payment;
+        self.send().direct(
+            &caller,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &payment.amount,
+        );
+    }
+
+    #[view(getPendingBuy)]
+    #[storage_mapper("pendingBuy")]
+    fn pending_buy(&self, address: &ManagedAddress) -> SingleValueMapper<PendingBuy<Self::Api>>;
+}
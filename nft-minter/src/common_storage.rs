@@ -2,7 +2,7 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use crate::{
-    nft_attributes_builder::{CollectionHash, MediaType, Tag},
+    nft_attributes_builder::{CollectionHash, MediaType, Tag, Uri},
     nft_tier::TierName,
 };
 
@@ -16,6 +16,7 @@ pub struct BrandInfo<M: ManagedTypeApi> {
     pub collection_hash: CollectionHash<M>,
     pub token_display_name: ManagedBuffer<M>,
     pub media_type: MediaType<M>,
+    pub uri_base: Uri<M>,
     pub royalties: BigUint<M>,
     pub mint_period: TimePeriod,
     pub whitelist_expire_timestamp: u64,
@@ -29,7 +30,9 @@ pub struct TimePeriod {
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug, Clone,
+)]
 pub struct MintPrice<M: ManagedTypeApi> {
     pub token_id: EgldOrEsdtTokenIdentifier<M>,
     pub amount: BigUint<M>,
@@ -56,13 +59,13 @@ pub trait CommonStorageModule {
     #[storage_mapper("brandInfo")]
     fn brand_info(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<BrandInfo<Self::Api>>;
 
-    #[view(getPriceForTier)]
-    #[storage_mapper("priceForTier")]
-    fn price_for_tier(
+    #[view(getPricesForTier)]
+    #[storage_mapper("pricesForTier")]
+    fn prices_for_tier(
         &self,
         brand_id: &BrandId<Self::Api>,
         tier: &TierName<Self::Api>,
-    ) -> SingleValueMapper<MintPrice<Self::Api>>;
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
 
     #[view(getTagsForBrand)]
     #[storage_mapper("tagsForBrand")]
@@ -74,4 +77,16 @@ pub trait CommonStorageModule {
     #[view(getMintWhitelist)]
     #[storage_mapper("mintWhitelist")]
     fn mint_whitelist(&self, brand_id: &BrandId<Self::Api>) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getWhitelistQuota)]
+    #[storage_mapper("whitelistQuota")]
+    fn whitelist_quota(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<usize>;
+
+    #[view(isBrandCancelled)]
+    #[storage_mapper("isBrandCancelled")]
+    fn is_brand_cancelled(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<bool>;
 }
@@ -0,0 +1,205 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc_modules::pause;
+
+use crate::{
+    common_storage::{BrandId, MintPrice, PaymentsVec},
+    nft_tier::TierName,
+};
+
+pub type RecipeId = usize;
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug, Clone,
+)]
+pub struct RecipeInput<M: ManagedTypeApi> {
+    pub brand_id: BrandId<M>,
+    pub tier: TierName<M>,
+    pub count: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct CraftingRecipe<M: ManagedTypeApi> {
+    pub inputs: ManagedVec<M, RecipeInput<M>>,
+    pub fee: Option<MintPrice<M>>,
+    pub output_brand_id: BrandId<M>,
+    pub output_tier: TierName<M>,
+}
+
+/// Players burn a set of NFTs, possibly from several brands and tiers, plus an optional fee,
+/// to receive a newly minted NFT of the recipe's output tier.
+/// Fees are added to the output brand's mint payments.
+#[multiversx_sc::module]
+pub trait CraftingModule:
+    crate::nft_minting::NftMintingModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+    + crate::tier_pricing::TierPricingModule
+    + crate::mint_limits::MintLimitsModule
+    + crate::mint_phases::MintPhasesModule
+    + crate::merkle_whitelist::MerkleWhitelistModule
+    + crate::mint_signature::MintSignatureModule
+    + crate::delayed_reveal::DelayedRevealModule
+    + crate::nft_updates::NftUpdatesModule
+    + crate::royalties::RoyaltiesModule
+    + crate::refunds::RefundsModule
+    + crate::referrals::ReferralsModule
+    + crate::platform_fee::PlatformFeeModule
+    + crate::admin_whitelist::AdminWhitelistModule
+    + crate::nft_attributes_builder::NftAttributesBuilderModule
+    + crate::events::EventsModule
+    + pause::PauseModule
+{
+    /// A `fee_amount` of 0 means there is no fee.
+    /// Fees are paid in the same multi-transfer as the NFTs, so they must be ESDTs.
+    #[endpoint(addCraftingRecipe)]
+    fn add_crafting_recipe(
+        &self,
+        output_brand_id: BrandId<Self::Api>,
+        output_tier: TierName<Self::Api>,
+        fee_token_id: EgldOrEsdtTokenIdentifier,
+        fee_amount: BigUint,
+        inputs: MultiValueEncoded<MultiValue3<BrandId<Self::Api>, TierName<Self::Api>, usize>>,
+    ) -> RecipeId {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&output_brand_id, &output_tier);
+        require!(!inputs.is_empty(), "Recipe must have at least one input");
+
+        let mut recipe_inputs = ManagedVec::<Self::Api, RecipeInput<Self::Api>>::new();
+        for input in inputs {
+            let (brand_id, tier, count) = input.into_tuple();
+            self.require_valid_brand_and_tier(&brand_id, &tier);
+            require!(count > 0, "Invalid input count");
+
+            let is_duplicate = recipe_inputs
+                .iter()
+                .any(|existing| existing.brand_id == brand_id && existing.tier == tier);
+            require!(!is_duplicate, "Duplicate recipe input");
+
+            recipe_inputs.push(RecipeInput {
+                brand_id,
+                tier,
+                count,
+            });
+        }
+
+        let fee = if fee_amount > 0 {
+            require!(
+                fee_token_id.is_esdt() && fee_token_id.is_valid(),
+                "Invalid fee token"
+            );
+
+            Some(MintPrice {
+                token_id: fee_token_id,
+                amount: fee_amount,
+            })
+        } else {
+            None
+        };
+
+        self.crafting_recipes().push(&CraftingRecipe {
+            inputs: recipe_inputs,
+            fee,
+            output_brand_id,
+            output_tier,
+        })
+    }
+
+    #[payable("*")]
+    #[endpoint(craft)]
+    fn craft(&self, recipe_id: RecipeId) -> PaymentsVec<Self::Api> {
+        self.require_not_paused();
+
+        let recipes_mapper = self.crafting_recipes();
+        require!(
+            recipe_id > 0 && recipe_id <= recipes_mapper.len(),
+            "Invalid recipe ID"
+        );
+
+        let recipe = recipes_mapper.get(recipe_id);
+        self.burn_recipe_inputs(&recipe);
+
+        let caller = self.blockchain().get_caller();
+        let brand_info = self.brand_info(&recipe.output_brand_id).get();
+        let output_payments = self.mint_and_send_random_nft(
+            &caller,
+            &recipe.output_brand_id,
+            &recipe.output_tier,
+            &brand_info,
+            1,
+        );
+
+        self.nft_crafted_event(&caller, recipe_id);
+
+        output_payments
+    }
+
+    fn burn_recipe_inputs(&self, recipe: &CraftingRecipe<Self::Api>) {
+        let mut input_token_ids = ManagedVec::<Self::Api, TokenIdentifier>::new();
+        let mut received_counts = ManagedVec::<Self::Api, u64>::new();
+        for input in &recipe.inputs {
+            input_token_ids.push(self.nft_token(&input.brand_id).get_token_id());
+            received_counts.push(0);
+        }
+
+        let mut fee_paid = BigUint::zero();
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        for payment in &payments {
+            if payment.token_nonce == 0 {
+                let is_fee_token = match &recipe.fee {
+                    Some(fee) => fee.token_id == payment.token_identifier,
+                    None => false,
+                };
+                require!(is_fee_token, "Invalid payment");
+
+                fee_paid += &payment.amount;
+                continue;
+            }
+
+            let opt_input_index = (0..recipe.inputs.len()).find(|&i| {
+                let input = recipe.inputs.get(i);
+                *input_token_ids.get(i) == payment.token_identifier
+                    && self
+                        .tier_for_nft(&input.brand_id, payment.token_nonce)
+                        .get()
+                        == input.tier
+            });
+            let input_index = match opt_input_index {
+                Some(index) => index,
+                None => sc_panic!("Invalid payment"),
+            };
+
+            let amount = match payment.amount.to_u64() {
+                Some(amount) => amount,
+                None => sc_panic!("Invalid payment"),
+            };
+            let received_count = received_counts.get(input_index);
+            let _ = received_counts.set(input_index, received_count + amount);
+
+            self.send().esdt_local_burn(
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            );
+        }
+
+        for (i, input) in recipe.inputs.iter().enumerate() {
+            require!(
+                received_counts.get(i) == input.count as u64,
+                "Payment does not match recipe"
+            );
+        }
+
+        if let Some(fee) = &recipe.fee {
+            require!(fee_paid == fee.amount, "Invalid fee");
+            self.add_mint_payment(&recipe.output_brand_id, fee.token_id.clone(), fee_paid);
+        }
+    }
+
+    #[view(getCraftingRecipes)]
+    #[storage_mapper("craftingRecipes")]
+    fn crafting_recipes(&self) -> VecMapper<CraftingRecipe<Self::Api>>;
+}
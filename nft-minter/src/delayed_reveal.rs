@@ -0,0 +1,192 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    brand_creation::INVALID_BRAND_ID_ERR_MSG, common_storage::BrandId, nft_attributes_builder::Uri,
+    nft_tier::TierName,
+};
+
+pub const REVEAL_BLOCK_DELAY: u64 = 10;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct PlaceholderInfo<M: ManagedTypeApi> {
+    pub tier: TierName<M>,
+    pub placeholder_id: UniqueId,
+}
+
+/// In delayed reveal mode, buyers receive placeholder NFTs, numbered in purchase order.
+/// The real IDs are only known after the reveal, when each tier's placeholder IDs are shifted
+/// by a random offset. The offset comes from the random seed of the block `reveal` is called in,
+/// which is at or after the block set when the reveal was scheduled, once minting is closed.
+///
+/// Only dynamic brands may use delayed reveal, as the placeholders are then updated
+/// wherever they are held, see `NftUpdatesModule`.
+#[multiversx_sc::module]
+pub trait DelayedRevealModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+    + crate::nft_attributes_builder::NftAttributesBuilderModule
+    + crate::nft_updates::NftUpdatesModule
+{
+    #[endpoint(enableDelayedReveal)]
+    fn enable_delayed_reveal(&self, brand_id: BrandId<Self::Api>, placeholder_uri: Uri<Self::Api>) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(
+            self.is_valid_uri_base(&placeholder_uri),
+            "Invalid placeholder URI"
+        );
+        require!(
+            !self.has_minted_nfts(&brand_id),
+            "May not enable delayed reveal after minting started"
+        );
+        require!(
+            self.is_dynamic_brand(&brand_id).get(),
+            "Delayed reveal requires a dynamic brand"
+        );
+
+        self.placeholder_uri(&brand_id).set(placeholder_uri);
+    }
+
+    /// Minting is closed once the reveal is scheduled
+    #[endpoint(scheduleReveal)]
+    fn schedule_reveal(&self, brand_id: BrandId<Self::Api>) -> u64 {
+        self.require_caller_is_admin();
+        self.require_delayed_reveal_brand(&brand_id);
+
+        let reveal_block_mapper = self.reveal_block_nonce(&brand_id);
+        require!(reveal_block_mapper.is_empty(), "Reveal already scheduled");
+
+        let reveal_block_nonce = self.blockchain().get_block_nonce() + REVEAL_BLOCK_DELAY;
+        reveal_block_mapper.set(reveal_block_nonce);
+
+        reveal_block_nonce
+    }
+
+    #[endpoint(reveal)]
+    fn reveal(&self, brand_id: BrandId<Self::Api>) {
+        self.require_caller_is_admin();
+        self.require_delayed_reveal_brand(&brand_id);
+
+        let reveal_block_mapper = self.reveal_block_nonce(&brand_id);
+        require!(!reveal_block_mapper.is_empty(), "Reveal not scheduled");
+        require!(
+            self.blockchain().get_block_nonce() >= reveal_block_mapper.get(),
+            "Reveal block not reached yet"
+        );
+        require!(!self.is_revealed(&brand_id).get(), "Already revealed");
+
+        for tier in self.nft_tiers_for_brand(&brand_id).iter() {
+            let total_nfts = self.total_nfts(&brand_id, &tier).get();
+            let offset = self.get_random_usize(0, total_nfts);
+            self.reveal_offset(&brand_id, &tier).set(offset);
+        }
+
+        self.is_revealed(&brand_id).set(true);
+    }
+
+    /// Placeholders keep their nonce. Their name, attributes and URIs are replaced with the real NFT's.
+    #[endpoint(revealNfts)]
+    fn reveal_nfts(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        placeholder_nonces: MultiValueEncoded<u64>,
+    ) {
+        self.require_caller_is_admin();
+        self.require_delayed_reveal_brand(&brand_id);
+        require!(self.is_revealed(&brand_id).get(), "Not revealed yet");
+        require!(!placeholder_nonces.is_empty(), "No placeholders provided");
+
+        let brand_info = self.brand_info(&brand_id).get();
+        for nonce in placeholder_nonces {
+            let placeholder_info_mapper = self.placeholder_info(&brand_id, nonce);
+            require!(!placeholder_info_mapper.is_empty(), "Not a placeholder NFT");
+
+            let placeholder_info = placeholder_info_mapper.take();
+            let nft_id = self.get_revealed_nft_id(&brand_id, &placeholder_info);
+            let nft_name =
+                self.get_nft_name_with_tag(brand_info.token_display_name.clone(), nft_id);
+            let attributes =
+                self.build_nft_attributes(&brand_info.collection_hash, &brand_id, nft_id);
+            let uris = self.build_nft_uris(&brand_info, nft_id);
+            self.update_nft_metadata(&brand_id, nonce, nft_name, &attributes, uris);
+        }
+    }
+
+    fn is_delayed_reveal_brand(&self, brand_id: &BrandId<Self::Api>) -> bool {
+        !self.placeholder_uri(brand_id).is_empty()
+    }
+
+    fn require_delayed_reveal_brand(&self, brand_id: &BrandId<Self::Api>) {
+        require!(
+            self.registered_brands().contains(brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(
+            self.is_delayed_reveal_brand(brand_id),
+            "Brand does not use delayed reveal"
+        );
+    }
+
+    /// Placeholders take the IDs in order, so the NFT ID can't be known at purchase time
+    fn get_next_placeholder_id(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> UniqueId {
+        let mut id_mapper = self.available_ids(brand_id, tier);
+        let last_id_index = id_mapper.len();
+        require!(last_id_index > 0, "No more NFTs available for brand");
+
+        let total_nfts = self.total_nfts(brand_id, tier).get();
+        let _ = id_mapper.swap_remove(last_id_index);
+
+        total_nfts - last_id_index + 1
+    }
+
+    fn get_revealed_nft_id(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        placeholder_info: &PlaceholderInfo<Self::Api>,
+    ) -> UniqueId {
+        let tier = &placeholder_info.tier;
+        let total_nfts = self.total_nfts(brand_id, tier).get();
+        let offset = self.reveal_offset(brand_id, tier).get();
+        let id_offset = self.nft_id_offset_for_tier(brand_id, tier).get();
+
+        (placeholder_info.placeholder_id - 1 + offset) % total_nfts + 1 + id_offset
+    }
+
+    #[view(getPlaceholderUri)]
+    #[storage_mapper("placeholderUri")]
+    fn placeholder_uri(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<Uri<Self::Api>>;
+
+    #[view(getRevealBlockNonce)]
+    #[storage_mapper("revealBlockNonce")]
+    fn reveal_block_nonce(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<u64>;
+
+    #[view(isRevealed)]
+    #[storage_mapper("isRevealed")]
+    fn is_revealed(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<bool>;
+
+    #[view(getRevealOffset)]
+    #[storage_mapper("revealOffset")]
+    fn reveal_offset(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> SingleValueMapper<usize>;
+
+    #[view(getPlaceholderInfo)]
+    #[storage_mapper("placeholderInfo")]
+    fn placeholder_info(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        placeholder_nonce: u64,
+    ) -> SingleValueMapper<PlaceholderInfo<Self::Api>>;
+}
@@ -1,7 +1,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::{common_storage::BrandId, nft_tier::TierName};
+use crate::{
+    common_storage::{BrandId, TimePeriod},
+    nft_tier::TierName,
+};
 
 #[multiversx_sc::module]
 pub trait EventsModule {
@@ -21,6 +24,25 @@ pub trait EventsModule {
         nr_nfts_bought: usize,
     );
 
+    #[event("referral")]
+    fn referral_event(
+        &self,
+        #[indexed] referrer_address: &ManagedAddress,
+        #[indexed] buyer_address: &ManagedAddress,
+        #[indexed] brand_id: &BrandId<Self::Api>,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        reward_amount: &BigUint,
+    );
+
+    #[event("nftRedeemed")]
+    fn nft_redeemed_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] program_id: usize);
+
+    #[event("nftCrafted")]
+    fn nft_crafted_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] recipe_id: usize);
+
+    #[event("brandCancelled")]
+    fn brand_cancelled_event(&self, #[indexed] brand_id: &BrandId<Self::Api>);
+
     #[event("nftGiveaway")]
     fn nft_giveaway_event(
         &self,
@@ -28,4 +50,20 @@ pub trait EventsModule {
         #[indexed] tier: &TierName<Self::Api>,
         total_nfts_given: usize,
     );
+
+    #[event("tierPriceChanged")]
+    fn tier_price_changed_event(
+        &self,
+        #[indexed] brand_id: &BrandId<Self::Api>,
+        #[indexed] tier: &TierName<Self::Api>,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("mintPeriodChanged")]
+    fn mint_period_changed_event(
+        &self,
+        #[indexed] brand_id: &BrandId<Self::Api>,
+        mint_period: &TimePeriod,
+    );
 }
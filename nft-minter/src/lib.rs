@@ -4,14 +4,28 @@ multiversx_sc::imports!();
 
 pub mod admin_whitelist;
 pub mod brand_creation;
+pub mod commit_reveal_buy;
 pub mod common_storage;
+pub mod crafting;
+pub mod delayed_reveal;
 pub mod events;
+pub mod merkle_whitelist;
+pub mod mint_limits;
+pub mod mint_phases;
+pub mod mint_signature;
 pub mod nft_attributes_builder;
 pub mod nft_marketplace_interactor;
 pub mod nft_minter_proxy;
 pub mod nft_minting;
 pub mod nft_tier;
+pub mod nft_updates;
+pub mod platform_fee;
+pub mod redemption;
+pub mod referrals;
+pub mod refunds;
 pub mod royalties;
+pub mod storage_migration;
+pub mod tier_pricing;
 pub mod views;
 
 use multiversx_sc_modules::pause;
@@ -22,10 +36,24 @@ pub trait NftMinter:
     + admin_whitelist::AdminWhitelistModule
     + brand_creation::BrandCreationModule
     + nft_minting::NftMintingModule
+    + commit_reveal_buy::CommitRevealBuyModule
+    + redemption::RedemptionModule
+    + crafting::CraftingModule
+    + delayed_reveal::DelayedRevealModule
+    + mint_limits::MintLimitsModule
+    + mint_phases::MintPhasesModule
+    + merkle_whitelist::MerkleWhitelistModule
+    + mint_signature::MintSignatureModule
     + nft_tier::NftTierModule
+    + nft_updates::NftUpdatesModule
+    + tier_pricing::TierPricingModule
     + nft_attributes_builder::NftAttributesBuilderModule
     + royalties::RoyaltiesModule
+    + platform_fee::PlatformFeeModule
+    + refunds::RefundsModule
+    + referrals::ReferralsModule
     + nft_marketplace_interactor::NftMarketplaceInteractorModule
+    + storage_migration::StorageMigrationModule
     + views::ViewsModule
     + events::EventsModule
     + pause::PauseModule
@@ -36,12 +64,17 @@ pub trait NftMinter:
         royalties_claim_address: ManagedAddress,
         mint_payments_claim_address: ManagedAddress,
         max_nfts_per_transaction: usize,
+        platform_fee_address: ManagedAddress,
+        platform_fee: u32,
         opt_admin: OptionalValue<ManagedAddress>,
     ) {
         self.royalties_claim_address().set(&royalties_claim_address);
         self.mint_payments_claim_address()
             .set(&mint_payments_claim_address);
         self.set_max_nfts_per_transaction(max_nfts_per_transaction);
+        self.set_platform_fee(platform_fee_address, platform_fee);
+        self.storage_version()
+            .set(storage_migration::CURRENT_STORAGE_VERSION);
 
         if let OptionalValue::Some(admin) = opt_admin {
             self.add_user_to_admin_list(admin);
@@ -54,12 +87,16 @@ pub trait NftMinter:
         royalties_claim_address: ManagedAddress,
         mint_payments_claim_address: ManagedAddress,
         max_nfts_per_transaction: usize,
+        platform_fee_address: ManagedAddress,
+        platform_fee: u32,
         opt_admin: OptionalValue<ManagedAddress>,
     ) {
         self.royalties_claim_address().set(&royalties_claim_address);
         self.mint_payments_claim_address()
             .set(&mint_payments_claim_address);
         self.set_max_nfts_per_transaction(max_nfts_per_transaction);
+        self.set_platform_fee(platform_fee_address, platform_fee);
+        self.migrate_storage();
 
         if let OptionalValue::Some(admin) = opt_admin {
             self.add_user_to_admin_list(admin);
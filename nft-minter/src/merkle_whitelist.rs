@@ -0,0 +1,133 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc::api::KECCAK256_RESULT_LEN;
+
+use crate::{brand_creation::INVALID_BRAND_ID_ERR_MSG, common_storage::BrandId};
+
+pub type MerkleHash<M> = ManagedByteArray<M, KECCAK256_RESULT_LEN>;
+
+/// Leaves are `keccak256(address ++ quota)`, with the quota encoded as big endian u64.
+/// Pairs are sorted before hashing, so the proof does not need to contain the node positions.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct MerkleWhitelistProof<M: ManagedTypeApi> {
+    pub quota: usize,
+    pub proof: ManagedVec<M, MerkleHash<M>>,
+}
+
+#[multiversx_sc::module]
+pub trait MerkleWhitelistModule:
+    crate::admin_whitelist::AdminWhitelistModule + crate::common_storage::CommonStorageModule
+{
+    #[endpoint(setWhitelistMerkleRoot)]
+    fn set_whitelist_merkle_root(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        merkle_root: MerkleHash<Self::Api>,
+    ) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+
+        self.whitelist_merkle_root(&brand_id).set(merkle_root);
+    }
+
+    #[endpoint(clearWhitelistMerkleRoot)]
+    fn clear_whitelist_merkle_root(&self, brand_id: BrandId<Self::Api>) {
+        self.require_caller_is_admin();
+
+        self.whitelist_merkle_root(&brand_id).clear();
+    }
+
+    fn consume_merkle_whitelist_quota(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        address: &ManagedAddress,
+        whitelist_proof: &MerkleWhitelistProof<Self::Api>,
+        nfts_to_buy: usize,
+    ) {
+        let root_mapper = self.whitelist_merkle_root(brand_id);
+        require!(!root_mapper.is_empty(), "No Merkle root set for brand");
+
+        let leaf = self.compute_merkle_leaf(address, whitelist_proof.quota);
+        require!(
+            self.verify_merkle_proof(&root_mapper.get(), leaf, &whitelist_proof.proof),
+            "Invalid Merkle proof"
+        );
+
+        let minted_mapper = self.merkle_whitelist_minted_count(brand_id, address);
+        let new_minted_count = minted_mapper.get() + nfts_to_buy;
+        require!(
+            new_minted_count <= whitelist_proof.quota,
+            "Whitelist quota exceeded"
+        );
+
+        minted_mapper.set(new_minted_count);
+    }
+
+    fn restore_merkle_whitelist_quota(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        address: &ManagedAddress,
+        nfts_removed: usize,
+    ) {
+        self.merkle_whitelist_minted_count(brand_id, address)
+            .update(|minted_count| *minted_count -= nfts_removed);
+    }
+
+    fn compute_merkle_leaf(&self, address: &ManagedAddress, quota: usize) -> MerkleHash<Self::Api> {
+        let mut leaf_data = address.as_managed_buffer().clone();
+        leaf_data.append_bytes(&(quota as u64).to_be_bytes());
+
+        self.crypto().keccak256(leaf_data)
+    }
+
+    fn verify_merkle_proof(
+        &self,
+        merkle_root: &MerkleHash<Self::Api>,
+        leaf: MerkleHash<Self::Api>,
+        proof: &ManagedVec<MerkleHash<Self::Api>>,
+    ) -> bool {
+        let mut computed_hash = leaf;
+        for proof_element in proof.iter() {
+            computed_hash = self.hash_merkle_pair(&computed_hash, &proof_element);
+        }
+
+        &computed_hash == merkle_root
+    }
+
+    fn hash_merkle_pair(
+        &self,
+        first: &MerkleHash<Self::Api>,
+        second: &MerkleHash<Self::Api>,
+    ) -> MerkleHash<Self::Api> {
+        let (lower, higher) = if first.to_byte_array() <= second.to_byte_array() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        let mut pair_data = lower.as_managed_buffer().clone();
+        pair_data.append(higher.as_managed_buffer());
+
+        self.crypto().keccak256(pair_data)
+    }
+
+    #[view(getWhitelistMerkleRoot)]
+    #[storage_mapper("whitelistMerkleRoot")]
+    fn whitelist_merkle_root(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> SingleValueMapper<MerkleHash<Self::Api>>;
+
+    #[view(getMerkleWhitelistMintedCount)]
+    #[storage_mapper("merkleWhitelistMintedCount")]
+    fn merkle_whitelist_minted_count(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<usize>;
+}
@@ -0,0 +1,110 @@
+multiversx_sc::imports!();
+
+use crate::{
+    brand_creation::INVALID_BRAND_ID_ERR_MSG, common_storage::BrandId, nft_tier::TierName,
+};
+
+/// A limit of 0 means there is no limit
+#[multiversx_sc::module]
+pub trait MintLimitsModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+{
+    #[endpoint(setMaxNftsPerAddress)]
+    fn set_max_nfts_per_address(&self, brand_id: BrandId<Self::Api>, max: usize) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+
+        self.max_nfts_per_address(&brand_id).set(max);
+    }
+
+    #[endpoint(setMaxNftsPerAddressForTier)]
+    fn set_max_nfts_per_address_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        max: usize,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+
+        self.max_nfts_per_address_for_tier(&brand_id, &tier)
+            .set(max);
+    }
+
+    fn add_minted_nfts_for_address(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        address: &ManagedAddress,
+        nfts_minted: usize,
+    ) {
+        let max_per_address = self.max_nfts_per_address(brand_id).get();
+        let new_minted_count = self.minted_count_for_address(brand_id, address).get() + nfts_minted;
+        require!(
+            max_per_address == 0 || new_minted_count <= max_per_address,
+            "Max NFTs per address limit exceeded"
+        );
+
+        let max_per_address_for_tier = self.max_nfts_per_address_for_tier(brand_id, tier).get();
+        let new_minted_count_for_tier = self
+            .minted_count_for_address_and_tier(brand_id, tier, address)
+            .get()
+            + nfts_minted;
+        require!(
+            max_per_address_for_tier == 0 || new_minted_count_for_tier <= max_per_address_for_tier,
+            "Max NFTs per address for tier limit exceeded"
+        );
+
+        self.minted_count_for_address(brand_id, address)
+            .set(new_minted_count);
+        self.minted_count_for_address_and_tier(brand_id, tier, address)
+            .set(new_minted_count_for_tier);
+    }
+
+    fn remove_minted_nfts_for_address(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        address: &ManagedAddress,
+        nfts_removed: usize,
+    ) {
+        self.minted_count_for_address(brand_id, address)
+            .update(|minted_count| *minted_count -= nfts_removed);
+        self.minted_count_for_address_and_tier(brand_id, tier, address)
+            .update(|minted_count| *minted_count -= nfts_removed);
+    }
+
+    #[view(getMaxNftsPerAddress)]
+    #[storage_mapper("maxNftsPerAddress")]
+    fn max_nfts_per_address(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<usize>;
+
+    #[view(getMaxNftsPerAddressForTier)]
+    #[storage_mapper("maxNftsPerAddressForTier")]
+    fn max_nfts_per_address_for_tier(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> SingleValueMapper<usize>;
+
+    #[view(getMintedCountForAddress)]
+    #[storage_mapper("mintedCountForAddress")]
+    fn minted_count_for_address(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<usize>;
+
+    #[view(getMintedCountForAddressAndTier)]
+    #[storage_mapper("mintedCountForAddressAndTier")]
+    fn minted_count_for_address_and_tier(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<usize>;
+}
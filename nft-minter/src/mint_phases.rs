@@ -0,0 +1,279 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    brand_creation::{INVALID_BRAND_ID_ERR_MSG, INVALID_TIER_ERR_MSG},
+    common_storage::{BrandId, MintPrice, TimePeriod},
+    nft_tier::TierName,
+};
+
+pub type PhaseId = usize;
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug, Clone,
+)]
+pub struct TierPriceOverride<M: ManagedTypeApi> {
+    pub tier: TierName<M>,
+    pub price: MintPrice<M>,
+}
+
+/// A `max_nfts_per_address` of 0 means there is no limit.
+/// Tiers with a price override are sold at that price for the duration of the phase,
+/// the others keep their own prices.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct MintPhase<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub time_period: TimePeriod,
+    pub price_overrides: ManagedVec<M, TierPriceOverride<M>>,
+    pub max_nfts_per_address: usize,
+    pub is_public: bool,
+}
+
+/// Phases are optional. Once a brand has phases, buying is only possible during one of them,
+/// on top of the brand's mint period and whitelist rules.
+#[multiversx_sc::module]
+pub trait MintPhasesModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+{
+    #[endpoint(addMintPhase)]
+    fn add_mint_phase(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        name: ManagedBuffer,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        max_nfts_per_address: usize,
+        is_public: bool,
+        price_overrides: MultiValueEncoded<
+            MultiValue3<TierName<Self::Api>, EgldOrEsdtTokenIdentifier, BigUint>,
+        >,
+    ) -> PhaseId {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(start_timestamp < end_timestamp, "Invalid timestamps");
+
+        let mut phases_mapper = self.mint_phases(&brand_id);
+        if !phases_mapper.is_empty() {
+            let last_phase = phases_mapper.get(phases_mapper.len());
+            require!(
+                start_timestamp >= last_phase.time_period.end,
+                "Mint phases may not overlap"
+            );
+        }
+
+        let tiers_mapper = self.nft_tiers_for_brand(&brand_id);
+        let mut tier_price_overrides = ManagedVec::<Self::Api, TierPriceOverride<Self::Api>>::new();
+        for price_override in price_overrides {
+            let (tier, token_id, amount) = price_override.into_tuple();
+            require!(tiers_mapper.contains(&tier), INVALID_TIER_ERR_MSG);
+            require!(token_id.is_valid(), "Invalid price token");
+            require!(
+                !tier_price_overrides
+                    .iter()
+                    .any(|existing| existing.tier == tier),
+                "Duplicate tier price override"
+            );
+
+            tier_price_overrides.push(TierPriceOverride {
+                tier,
+                price: MintPrice { token_id, amount },
+            });
+        }
+
+        phases_mapper.push(&MintPhase {
+            name,
+            time_period: TimePeriod {
+                start: start_timestamp,
+                end: end_timestamp,
+            },
+            price_overrides: tier_price_overrides,
+            max_nfts_per_address,
+            is_public,
+        })
+    }
+
+    /// Phases added afterwards reuse the IDs, so their per-phase data is cleared as well
+    #[endpoint(clearMintPhases)]
+    fn clear_mint_phases(&self, brand_id: BrandId<Self::Api>) {
+        self.require_caller_is_admin();
+        require!(
+            !self.has_minted_nfts(&brand_id),
+            "May not change mint phases after minting started"
+        );
+
+        let mut phases_mapper = self.mint_phases(&brand_id);
+        for phase_id in 1..=phases_mapper.len() {
+            self.phase_eligibility_list(&brand_id, phase_id).clear();
+            self.minted_count_for_phase(&brand_id, phase_id).clear();
+        }
+
+        phases_mapper.clear();
+    }
+
+    #[endpoint(addToPhaseEligibilityList)]
+    fn add_to_phase_eligibility_list(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        phase_id: PhaseId,
+        users: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_phase(&brand_id, phase_id);
+
+        let mut mapper = self.phase_eligibility_list(&brand_id, phase_id);
+        for user in users {
+            let _ = mapper.insert(user);
+        }
+    }
+
+    #[endpoint(removeFromPhaseEligibilityList)]
+    fn remove_from_phase_eligibility_list(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        phase_id: PhaseId,
+        users: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_phase(&brand_id, phase_id);
+
+        let mut mapper = self.phase_eligibility_list(&brand_id, phase_id);
+        for user in users {
+            let _ = mapper.swap_remove(&user);
+        }
+    }
+
+    #[view(getActivePhase)]
+    fn get_active_phase_view(
+        &self,
+        brand_id: BrandId<Self::Api>,
+    ) -> OptionalValue<MultiValue2<PhaseId, MintPhase<Self::Api>>> {
+        match self.get_active_phase(&brand_id) {
+            Some((phase_id, phase)) => OptionalValue::Some((phase_id, phase).into()),
+            None => OptionalValue::None,
+        }
+    }
+
+    #[view(getMintedCountForPhase)]
+    fn get_minted_count_for_phase(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        phase_id: PhaseId,
+        address: ManagedAddress,
+    ) -> usize {
+        self.minted_count_for_phase(&brand_id, phase_id)
+            .get(&address)
+            .unwrap_or_default()
+    }
+
+    fn get_active_phase(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> Option<(PhaseId, MintPhase<Self::Api>)> {
+        let current_timestamp = self
+            .blockchain()
+            .get_block_timestamp_seconds()
+            .as_u64_seconds();
+        let phases_mapper = self.mint_phases(brand_id);
+        for phase_id in 1..=phases_mapper.len() {
+            let phase = phases_mapper.get(phase_id);
+            if current_timestamp < phase.time_period.start {
+                break;
+            }
+            if current_timestamp < phase.time_period.end {
+                return Some((phase_id, phase));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the active phase, if the brand has phases, and its price override for the tier, if any
+    fn apply_mint_phase_rules(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        address: &ManagedAddress,
+        nfts_to_buy: usize,
+    ) -> (Option<PhaseId>, Option<MintPrice<Self::Api>>) {
+        if self.mint_phases(brand_id).is_empty() {
+            return (None, None);
+        }
+
+        let (phase_id, phase) = match self.get_active_phase(brand_id) {
+            Some(active_phase) => active_phase,
+            None => sc_panic!("No active mint phase"),
+        };
+        require!(
+            phase.is_public
+                || self
+                    .phase_eligibility_list(brand_id, phase_id)
+                    .contains(address),
+            "Not eligible for mint phase"
+        );
+
+        let mut minted_count_mapper = self.minted_count_for_phase(brand_id, phase_id);
+        let new_minted_count = minted_count_mapper.get(address).unwrap_or_default() + nfts_to_buy;
+        require!(
+            phase.max_nfts_per_address == 0 || new_minted_count <= phase.max_nfts_per_address,
+            "Max NFTs per address for phase limit exceeded"
+        );
+        let _ = minted_count_mapper.insert(address.clone(), new_minted_count);
+
+        let opt_price_override = phase
+            .price_overrides
+            .iter()
+            .find(|price_override| &price_override.tier == tier)
+            .map(|price_override| price_override.price.clone());
+
+        (Some(phase_id), opt_price_override)
+    }
+
+    /// The phases may have been cleared and added again in the meantime
+    fn remove_minted_nfts_for_phase(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        phase_id: PhaseId,
+        address: &ManagedAddress,
+        nfts_removed: usize,
+    ) {
+        let mut minted_count_mapper = self.minted_count_for_phase(brand_id, phase_id);
+        if let Some(minted_count) = minted_count_mapper.get(address) {
+            let new_minted_count = minted_count - core::cmp::min(minted_count, nfts_removed);
+            let _ = minted_count_mapper.insert(address.clone(), new_minted_count);
+        }
+    }
+
+    fn require_valid_phase(&self, brand_id: &BrandId<Self::Api>, phase_id: PhaseId) {
+        let phases_mapper = self.mint_phases(brand_id);
+        require!(
+            phase_id > 0 && phase_id <= phases_mapper.len(),
+            "Invalid mint phase"
+        );
+    }
+
+    #[view(getMintPhases)]
+    #[storage_mapper("mintPhases")]
+    fn mint_phases(&self, brand_id: &BrandId<Self::Api>) -> VecMapper<MintPhase<Self::Api>>;
+
+    #[view(getPhaseEligibilityList)]
+    #[storage_mapper("phaseEligibilityList")]
+    fn phase_eligibility_list(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        phase_id: PhaseId,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("mintedCountForPhase")]
+    fn minted_count_for_phase(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        phase_id: PhaseId,
+    ) -> MapMapper<ManagedAddress, usize>;
+}
@@ -0,0 +1,115 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    brand_creation::INVALID_BRAND_ID_ERR_MSG, common_storage::BrandId, nft_tier::TierName,
+};
+
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+
+pub type Ed25519Signature<M> = ManagedByteArray<M, ED25519_SIGNATURE_LEN>;
+
+/// The signer signs the nested encoding of `(minter_address, caller, brand_id, tier, quantity, expiry, nonce)`,
+/// with the quantity encoded as u64. The minter's own address keeps the signature from being replayed on other minters.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct SignedMintAuthorization<M: ManagedTypeApi> {
+    pub expiry: u64,
+    pub nonce: u64,
+    pub signature: Ed25519Signature<M>,
+}
+
+#[multiversx_sc::module]
+pub trait MintSignatureModule:
+    crate::admin_whitelist::AdminWhitelistModule + crate::common_storage::CommonStorageModule
+{
+    /// Once a signer is set, every buy for the brand needs a signature from it
+    #[endpoint(setMintSigner)]
+    fn set_mint_signer(&self, brand_id: BrandId<Self::Api>, signer: ManagedAddress) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+
+        self.mint_signer(&brand_id).set(signer);
+    }
+
+    #[endpoint(clearMintSigner)]
+    fn clear_mint_signer(&self, brand_id: BrandId<Self::Api>) {
+        self.require_caller_is_admin();
+
+        self.mint_signer(&brand_id).clear();
+    }
+
+    fn require_valid_mint_signature(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        caller: &ManagedAddress,
+        quantity: usize,
+        authorization: &SignedMintAuthorization<Self::Api>,
+    ) {
+        let current_timestamp = self
+            .blockchain()
+            .get_block_timestamp_seconds()
+            .as_u64_seconds();
+        require!(
+            current_timestamp < authorization.expiry,
+            "Mint signature expired"
+        );
+
+        let used_nonces_mapper = self.used_mint_signature_nonces(brand_id);
+        require!(
+            !used_nonces_mapper.contains(&authorization.nonce),
+            "Mint signature already used"
+        );
+
+        let signer = self.mint_signer(brand_id).get();
+        let message = self.build_mint_signature_message(
+            caller,
+            brand_id,
+            tier,
+            quantity,
+            authorization.expiry,
+            authorization.nonce,
+        );
+        self.crypto().verify_ed25519(
+            signer.as_managed_buffer(),
+            &message,
+            authorization.signature.as_managed_buffer(),
+        );
+
+        used_nonces_mapper.add(&authorization.nonce);
+    }
+
+    fn build_mint_signature_message(
+        &self,
+        caller: &ManagedAddress,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        quantity: usize,
+        expiry: u64,
+        nonce: u64,
+    ) -> ManagedBuffer {
+        self.serializer().top_encode_to_managed_buffer(&(
+            self.blockchain().get_sc_address(),
+            caller,
+            brand_id,
+            tier,
+            quantity as u64,
+            expiry,
+            nonce,
+        ))
+    }
+
+    #[view(getMintSigner)]
+    #[storage_mapper("mintSigner")]
+    fn mint_signer(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("usedMintSignatureNonces")]
+    fn used_mint_signature_nonces(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> WhitelistMapper<Self::Api, u64>;
+}
@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use crate::common_storage::BrandId;
+use crate::common_storage::{BrandId, BrandInfo};
 
 static TAGS_PREFIX: &[u8] = b"tags:";
 static TAG_SEPARATOR: &[u8] = b",";
@@ -23,6 +23,7 @@ static SUPPORTED_MEDIA_TYPES: &[&[u8]] = &[
     b"webm",
 ];
 const MAX_MEDIA_TYPE_LEN: usize = 9;
+const MAX_URI_BASE_LEN: usize = 100;
 pub const COLLECTION_HASH_LEN: usize = 46;
 
 pub type Uri<M> = ManagedBuffer<M>;
@@ -84,14 +85,40 @@ pub trait NftAttributesBuilderModule: crate::common_storage::CommonStorageModule
         tags_attributes
     }
 
+    fn build_nft_uris(
+        &self,
+        brand_info: &BrandInfo<Self::Api>,
+        nft_id: UniqueId,
+    ) -> ManagedVec<Uri<Self::Api>> {
+        let nft_uri = self.build_nft_main_file_uri(
+            &brand_info.uri_base,
+            &brand_info.collection_hash,
+            nft_id,
+            &brand_info.media_type,
+        );
+        let nft_json =
+            self.build_nft_json_file_uri(&brand_info.uri_base, &brand_info.collection_hash, nft_id);
+        let collection_json =
+            self.build_collection_json_file_uri(&brand_info.uri_base, &brand_info.collection_hash);
+
+        let mut uris = ManagedVec::new();
+        uris.push(nft_uri);
+        uris.push(nft_json);
+        uris.push(collection_json);
+
+        uris
+    }
+
     fn build_nft_main_file_uri(
         &self,
+        uri_base: &Uri<Self::Api>,
         collection_hash: &CollectionHash<Self::Api>,
         nft_id: UniqueId,
         media_type: &MediaType<Self::Api>,
     ) -> Uri<Self::Api> {
         sc_format!(
-            "https://ipfs.io/ipfs/{}/{}.{}",
+            "{}{}/{}.{}",
+            uri_base,
             collection_hash.as_managed_buffer(),
             nft_id,
             media_type
@@ -100,11 +127,13 @@ pub trait NftAttributesBuilderModule: crate::common_storage::CommonStorageModule
 
     fn build_nft_json_file_uri(
         &self,
+        uri_base: &Uri<Self::Api>,
         collection_hash: &CollectionHash<Self::Api>,
         nft_id: UniqueId,
     ) -> Uri<Self::Api> {
         sc_format!(
-            "https://ipfs.io/ipfs/{}/{}.json",
+            "{}{}/{}.json",
+            uri_base,
             collection_hash.as_managed_buffer(),
             nft_id,
         )
@@ -112,14 +141,29 @@ pub trait NftAttributesBuilderModule: crate::common_storage::CommonStorageModule
 
     fn build_collection_json_file_uri(
         &self,
+        uri_base: &Uri<Self::Api>,
         collection_hash: &CollectionHash<Self::Api>,
     ) -> Uri<Self::Api> {
         sc_format!(
-            "https://ipfs.io/ipfs/{}/collection.json",
+            "{}{}/collection.json",
+            uri_base,
             collection_hash.as_managed_buffer(),
         )
     }
 
+    fn get_nft_name_with_tag(&self, name: ManagedBuffer, tag: usize) -> ManagedBuffer {
+        let mut nft_name = name;
+        nft_name.append(&sc_format!(" #{}", tag));
+        nft_name
+    }
+
+    /// The base is prepended as-is, so it must contain the trailing separator,
+    /// e.g. `https://ipfs.io/ipfs/` or `ipfs://`
+    fn is_valid_uri_base(&self, uri_base: &Uri<Self::Api>) -> bool {
+        let uri_base_len = uri_base.len();
+        uri_base_len > 0 && uri_base_len <= MAX_URI_BASE_LEN
+    }
+
     fn is_supported_media_type(&self, media_type: &MediaType<Self::Api>) -> bool {
         let media_type_len = media_type.len();
         if media_type_len > MAX_MEDIA_TYPE_LEN {
@@ -128,7 +172,7 @@ pub trait NftAttributesBuilderModule: crate::common_storage::CommonStorageModule
 
         let mut media_static_buffer = [0u8; MAX_MEDIA_TYPE_LEN];
         let slice = &mut media_static_buffer[..media_type_len];
-        let _ = media_type.load_slice(0, slice);
+        media_type.load_slice(0, slice);
 
         // clippy is wrong, using `slice` directly causes an error
         #[allow(clippy::redundant_slicing)]
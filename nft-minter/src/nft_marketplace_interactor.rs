@@ -2,7 +2,10 @@ multiversx_sc::imports!();
 
 use multiversx_sc_modules::pause;
 
-use crate::common_storage::{self, EgldValuePaymentsVecPair};
+use crate::{
+    brand_creation::INVALID_BRAND_ID_ERR_MSG,
+    common_storage::{self, BrandId, EgldValuePaymentsVecPair},
+};
 
 pub mod nft_marketplace_proxy {
     multiversx_sc::imports!();
@@ -25,14 +28,21 @@ pub trait NftMarketplaceInteractorModule:
     + pause::PauseModule
     + common_storage::CommonStorageModule
 {
+    /// The marketplace only reports the claimed amounts per payment token,
+    /// so everything claimed in one call is credited to the given brand
     #[endpoint(claimRoyaltiesFromMarketplace)]
     fn claim_royalties_from_marketplace(
         &self,
         marketplace_address: ManagedAddress,
+        brand_id: BrandId<Self::Api>,
         tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>,
     ) {
         self.require_caller_is_admin();
         self.require_not_paused();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
 
         let mut args = MultiValueEncoded::new();
         for token in tokens {
@@ -48,10 +58,10 @@ pub trait NftMarketplaceInteractorModule:
 
         let (egld_amount, other_payments) = call_result.into_tuple();
         if egld_amount > 0 {
-            self.add_royalties(EgldOrEsdtTokenIdentifier::egld(), egld_amount);
+            self.add_royalties(&brand_id, EgldOrEsdtTokenIdentifier::egld(), egld_amount);
         }
         if !other_payments.is_empty() {
-            self.add_royalties_multiple(&other_payments)
+            self.add_royalties_multiple(&brand_id, &other_payments)
         }
     }
 
@@ -47,13 +47,17 @@ where
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
         Arg2: ProxyArg<usize>,
-        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg4: ProxyArg<u32>,
+        Arg5: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         royalties_claim_address: Arg0,
         mint_payments_claim_address: Arg1,
         max_nfts_per_transaction: Arg2,
-        opt_admin: Arg3,
+        platform_fee_address: Arg3,
+        platform_fee: Arg4,
+        opt_admin: Arg5,
     ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -61,6 +65,8 @@ where
             .argument(&royalties_claim_address)
             .argument(&mint_payments_claim_address)
             .argument(&max_nfts_per_transaction)
+            .argument(&platform_fee_address)
+            .argument(&platform_fee)
             .argument(&opt_admin)
             .original_result()
     }
@@ -79,13 +85,17 @@ where
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
         Arg2: ProxyArg<usize>,
-        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg4: ProxyArg<u32>,
+        Arg5: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         royalties_claim_address: Arg0,
         mint_payments_claim_address: Arg1,
         max_nfts_per_transaction: Arg2,
-        opt_admin: Arg3,
+        platform_fee_address: Arg3,
+        platform_fee: Arg4,
+        opt_admin: Arg5,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -93,6 +103,8 @@ where
             .argument(&royalties_claim_address)
             .argument(&mint_payments_claim_address)
             .argument(&max_nfts_per_transaction)
+            .argument(&platform_fee_address)
+            .argument(&platform_fee)
             .argument(&opt_admin)
             .original_result()
     }
@@ -152,7 +164,7 @@ where
     >(
         self,
         brand_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getNftTokenIdForBrand")
@@ -160,17 +172,17 @@ where
             .original_result()
     }
 
-    pub fn price_for_tier<
+    pub fn prices_for_tier<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         brand_id: Arg0,
         tier: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MintPrice<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPriceForTier")
+            .raw_call("getPricesForTier")
             .argument(&brand_id)
             .argument(&tier)
             .original_result()
@@ -202,6 +214,35 @@ where
             .original_result()
     }
 
+    pub fn whitelist_quota<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWhitelistQuota")
+            .argument(&brand_id)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn is_brand_cancelled<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isBrandCancelled")
+            .argument(&brand_id)
+            .original_result()
+    }
+
     pub fn add_user_to_admin_list<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -228,39 +269,47 @@ where
             .original_result()
     }
 
+    /// Dynamic NFTs can have their attributes and URIs updated after mint, see `NftUpdatesModule`. 
+    /// Edition brands are SFT collections where each tier is a single edition, 
+    /// i.e. one nonce with as many copies as the tier's number of NFTs. 
     pub fn issue_token_for_brand<
         Arg0: ProxyArg<ManagedByteArray<Env::Api, 46usize>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg3: ProxyArg<BigUint<Env::Api>>,
-        Arg4: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
         Arg5: ProxyArg<u64>,
-        Arg6: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
-        Arg7: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg6: ProxyArg<u64>,
+        Arg7: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
         Arg8: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg9: ProxyArg<u64>,
-        Arg10: ProxyArg<ManagedVec<Env::Api, ManagedBuffer<Env::Api>>>,
-        Arg11: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedBuffer<Env::Api>, usize, BigUint<Env::Api>>>>,
+        Arg9: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg10: ProxyArg<u64>,
+        Arg11: ProxyArg<ManagedVec<Env::Api, ManagedBuffer<Env::Api>>>,
+        Arg12: ProxyArg<BrandTokenKind>,
+        Arg13: ProxyArg<MultiValueEncoded<Env::Api, MultiValue6<ManagedBuffer<Env::Api>, usize, BigUint<Env::Api>, Option<EgldOrEsdtTokenIdentifier<Env::Api>>, Option<PriceCurve<Env::Api>>, usize>>>,
     >(
         self,
         collection_hash: Arg0,
         brand_id: Arg1,
         media_type: Arg2,
-        royalties: Arg3,
-        mint_start_timestamp: Arg4,
-        mint_end_timestamp: Arg5,
-        mint_price_token_id: Arg6,
-        token_display_name: Arg7,
-        token_ticker: Arg8,
-        whitelist_expire_timestamp: Arg9,
-        tags: Arg10,
-        tier_name_nr_nfts_pairs: Arg11,
+        uri_base: Arg3,
+        royalties: Arg4,
+        mint_start_timestamp: Arg5,
+        mint_end_timestamp: Arg6,
+        mint_price_token_id: Arg7,
+        token_display_name: Arg8,
+        token_ticker: Arg9,
+        whitelist_expire_timestamp: Arg10,
+        tags: Arg11,
+        token_kind: Arg12,
+        tier_name_nr_nfts_pairs: Arg13,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("issueTokenForBrand")
             .argument(&collection_hash)
             .argument(&brand_id)
             .argument(&media_type)
+            .argument(&uri_base)
             .argument(&royalties)
             .argument(&mint_start_timestamp)
             .argument(&mint_end_timestamp)
@@ -269,23 +318,25 @@ where
             .argument(&token_ticker)
             .argument(&whitelist_expire_timestamp)
             .argument(&tags)
+            .argument(&token_kind)
             .argument(&tier_name_nr_nfts_pairs)
             .original_result()
     }
 
+    /// Each user may mint up to `quota` NFTs before the whitelist expires 
     pub fn add_to_whitelist<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, usize>>>,
     >(
         self,
         brand_id: Arg0,
-        users: Arg1,
+        user_quota_pairs: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("addToWhitelist")
             .argument(&brand_id)
-            .argument(&users)
+            .argument(&user_quota_pairs)
             .original_result()
     }
 
@@ -321,268 +372,1801 @@ where
             .original_result()
     }
 
+    pub fn set_mint_period<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        brand_id: Arg0,
+        mint_start_timestamp: Arg1,
+        mint_end_timestamp: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMintPeriod")
+            .argument(&brand_id)
+            .argument(&mint_start_timestamp)
+            .argument(&mint_end_timestamp)
+            .original_result()
+    }
+
+    pub fn set_uri_base_for_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        uri_base: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUriBaseForBrand")
+            .argument(&brand_id)
+            .argument(&uri_base)
+            .original_result()
+    }
+
+    /// Brands with a mint signer require a signature for every buy, which replaces the whitelist. 
+    /// Otherwise, a Merkle proof is only needed for addresses that are not in the `mint_whitelist` set, 
+    /// and only before the whitelist expires. 
+    /// The platform fee is kept out of the brand's mint payments 
     pub fn buy_random_nft<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg2: ProxyArg<OptionalValue<usize>>,
+        Arg3: ProxyArg<OptionalValue<MintProof<Env::Api>>>,
     >(
         self,
         brand_id: Arg0,
         tier: Arg1,
         opt_nfts_to_buy: Arg2,
+        opt_mint_proof: Arg3,
     ) -> TxTypedCall<Env, From, To, (), Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
         self.wrapped_tx
             .raw_call("buyRandomNft")
             .argument(&brand_id)
             .argument(&tier)
             .argument(&opt_nfts_to_buy)
+            .argument(&opt_mint_proof)
             .original_result()
     }
 
-    pub fn giveaway_nfts<
+    /// Same as `buyRandomNft`, but the referrer also gets its share of the payment 
+    pub fn buy_random_nft_with_referrer<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, usize>>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<usize>>,
+        Arg4: ProxyArg<OptionalValue<MintProof<Env::Api>>>,
     >(
         self,
         brand_id: Arg0,
         tier: Arg1,
-        dest_amount_pairs: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        referrer: Arg2,
+        opt_nfts_to_buy: Arg3,
+        opt_mint_proof: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
         self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("giveawayNfts")
+            .raw_call("buyRandomNftWithReferrer")
             .argument(&brand_id)
             .argument(&tier)
-            .argument(&dest_amount_pairs)
+            .argument(&referrer)
+            .argument(&opt_nfts_to_buy)
+            .argument(&opt_mint_proof)
             .original_result()
     }
 
-    pub fn nft_tiers_for_brand<
+    pub fn giveaway_nfts<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, usize>>>,
     >(
         self,
         brand_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        tier: Arg1,
+        dest_amount_pairs: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getNftTiersForBrand")
+            .raw_call("giveawayNfts")
             .argument(&brand_id)
+            .argument(&tier)
+            .argument(&dest_amount_pairs)
             .original_result()
     }
 
-    pub fn nft_id_offset_for_tier<
+    pub fn commit_buy<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<usize>>,
+        Arg3: ProxyArg<OptionalValue<MintProof<Env::Api>>>,
     >(
         self,
         brand_id: Arg0,
         tier: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        opt_nfts_to_buy: Arg2,
+        opt_mint_proof: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("nftIdOffsetForTier")
+            .raw_call("commitBuy")
             .argument(&brand_id)
             .argument(&tier)
+            .argument(&opt_nfts_to_buy)
+            .argument(&opt_mint_proof)
             .original_result()
     }
 
-    pub fn set_royalties_claim_address<
+    pub fn reveal_buy(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revealBuy")
+            .original_result()
+    }
+
+    pub fn refund_expired_buy(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refundExpiredBuy")
+            .original_result()
+    }
+
+    pub fn pending_buy<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        new_address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PendingBuy<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setRoyaltiesClaimAddress")
-            .argument(&new_address)
+            .raw_call("getPendingBuy")
+            .argument(&address)
             .original_result()
     }
 
-    pub fn change_royalties_for_brand<
+    pub fn add_redemption_program<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+        Arg6: ProxyArg<usize>,
+        Arg7: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
     >(
         self,
-        brand_id: Arg0,
-        new_royalties: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        input_brand_id: Arg0,
+        nr_input_nfts: Arg1,
+        output_brand_id: Arg2,
+        output_tier: Arg3,
+        start_timestamp: Arg4,
+        end_timestamp: Arg5,
+        max_redemptions: Arg6,
+        opt_input_tier: Arg7,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("changeRoyaltiesForBrand")
-            .argument(&brand_id)
-            .argument(&new_royalties)
+            .raw_call("addRedemptionProgram")
+            .argument(&input_brand_id)
+            .argument(&nr_input_nfts)
+            .argument(&output_brand_id)
+            .argument(&output_tier)
+            .argument(&start_timestamp)
+            .argument(&end_timestamp)
+            .argument(&max_redemptions)
+            .argument(&opt_input_tier)
             .original_result()
     }
 
-    pub fn set_mint_payments_claim_address<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    pub fn set_redemption_period<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
     >(
         self,
-        new_address: Arg0,
+        program_id: Arg0,
+        start_timestamp: Arg1,
+        end_timestamp: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setMintPaymentsClaimAddress")
-            .argument(&new_address)
+            .raw_call("setRedemptionPeriod")
+            .argument(&program_id)
+            .argument(&start_timestamp)
+            .argument(&end_timestamp)
             .original_result()
     }
 
-    pub fn claim_royalties(
+    pub fn set_max_redemptions<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>> {
+        program_id: Arg0,
+        max_redemptions: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("claimRoyalties")
+            .raw_call("setMaxRedemptions")
+            .argument(&program_id)
+            .argument(&max_redemptions)
             .original_result()
     }
 
-    pub fn claim_mint_payments(
+    pub fn redeem<
+        Arg0: ProxyArg<usize>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>> {
+        program_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
         self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("claimMintPayments")
+            .raw_call("redeem")
+            .argument(&program_id)
             .original_result()
     }
 
-    pub fn royalties_claim_address(
+    pub fn redemption_programs(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, RedemptionProgram<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getRoyaltiesClaimAddress")
+            .raw_call("getRedemptionPrograms")
             .original_result()
     }
 
-    pub fn mint_payments_claim_address(
+    pub fn redemptions_count<
+        Arg0: ProxyArg<usize>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        program_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMintPaymentsClaimAddress")
+            .raw_call("getRedemptionsCount")
+            .argument(&program_id)
             .original_result()
     }
 
-    pub fn accumulated_royalties(
+    /// A `fee_amount` of 0 means there is no fee. 
+    /// Fees are paid in the same multi-transfer as the NFTs, so they must be ESDTs. 
+    pub fn add_crafting_recipe<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, usize>>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        output_brand_id: Arg0,
+        output_tier: Arg1,
+        fee_token_id: Arg2,
+        fee_amount: Arg3,
+        inputs: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccumulatedRoyalties")
+            .raw_call("addCraftingRecipe")
+            .argument(&output_brand_id)
+            .argument(&output_tier)
+            .argument(&fee_token_id)
+            .argument(&fee_amount)
+            .argument(&inputs)
             .original_result()
     }
 
-    pub fn accumulated_mint_payments(
+    pub fn craft<
+        Arg0: ProxyArg<usize>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        recipe_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("craft")
+            .argument(&recipe_id)
+            .original_result()
+    }
+
+    pub fn crafting_recipes(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, CraftingRecipe<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccumulatedMintPayments")
+            .raw_call("getCraftingRecipes")
             .original_result()
     }
 
-    pub fn claim_royalties_from_marketplace<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    pub fn enable_delayed_reveal<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
-        marketplace_address: Arg0,
-        tokens: Arg1,
+        brand_id: Arg0,
+        placeholder_uri: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("claimRoyaltiesFromMarketplace")
-            .argument(&marketplace_address)
-            .argument(&tokens)
+            .raw_call("enableDelayedReveal")
+            .argument(&brand_id)
+            .argument(&placeholder_uri)
             .original_result()
     }
 
-    pub fn get_brand_info_view<
+    /// Minting is closed once the reveal is scheduled 
+    pub fn schedule_reveal<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         brand_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BrandInfoViewResultType<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getBrandInfo")
+            .raw_call("scheduleReveal")
             .argument(&brand_id)
             .original_result()
     }
 
-    pub fn get_all_brands_info(
+    pub fn reveal<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, BrandInfoViewResultType<Env::Api>>> {
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAllBrandsInfo")
+            .raw_call("reveal")
+            .argument(&brand_id)
             .original_result()
     }
 
-    pub fn pause_endpoint(
+    /// Placeholders keep their nonce. Their name, attributes and URIs are replaced with the real NFT's. 
+    pub fn reveal_nfts<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
         self,
+        brand_id: Arg0,
+        placeholder_nonces: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("pause")
+            .raw_call("revealNfts")
+            .argument(&brand_id)
+            .argument(&placeholder_nonces)
             .original_result()
     }
 
-    pub fn unpause_endpoint(
+    pub fn placeholder_uri<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("unpause")
+            .raw_call("getPlaceholderUri")
+            .argument(&brand_id)
             .original_result()
     }
 
-    pub fn paused_status(
+    pub fn reveal_block_nonce<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRevealBlockNonce")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn is_revealed<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
         self,
+        brand_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isPaused")
+            .raw_call("isRevealed")
+            .argument(&brand_id)
             .original_result()
     }
-}
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
-pub struct MintPrice<Api>
-where
-    Api: ManagedTypeApi,
-{
-    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
-    pub amount: BigUint<Api>,
-}
+    pub fn reveal_offset<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRevealOffset")
+            .argument(&brand_id)
+            .argument(&tier)
+            .original_result()
+    }
 
-#[type_abi]
-#[derive(TopEncode, TopDecode)]
-pub struct BrandInfoViewResultType<Api>
-where
-    Api: ManagedTypeApi,
-{
-    pub brand_id: ManagedBuffer<Api>,
-    pub nft_token_id: TokenIdentifier<Api>,
-    pub brand_info: BrandInfo<Api>,
-    pub tier_info_entries: ArrayVec<TierInfoEntry<Api>, 5usize>,
-}
+    pub fn placeholder_info<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        brand_id: Arg0,
+        placeholder_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PlaceholderInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPlaceholderInfo")
+            .argument(&brand_id)
+            .argument(&placeholder_nonce)
+            .original_result()
+    }
 
-#[type_abi]
+    pub fn set_max_nfts_per_address<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        brand_id: Arg0,
+        max: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxNftsPerAddress")
+            .argument(&brand_id)
+            .argument(&max)
+            .original_result()
+    }
+
+    pub fn set_max_nfts_per_address_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        max: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxNftsPerAddressForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&max)
+            .original_result()
+    }
+
+    pub fn max_nfts_per_address<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxNftsPerAddress")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn max_nfts_per_address_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxNftsPerAddressForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .original_result()
+    }
+
+    pub fn minted_count_for_address<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintedCountForAddress")
+            .argument(&brand_id)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn minted_count_for_address_and_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        address: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintedCountForAddressAndTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn add_mint_phase<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<usize>,
+        Arg5: ProxyArg<bool>,
+        Arg6: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedBuffer<Env::Api>, EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        brand_id: Arg0,
+        name: Arg1,
+        start_timestamp: Arg2,
+        end_timestamp: Arg3,
+        max_nfts_per_address: Arg4,
+        is_public: Arg5,
+        price_overrides: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addMintPhase")
+            .argument(&brand_id)
+            .argument(&name)
+            .argument(&start_timestamp)
+            .argument(&end_timestamp)
+            .argument(&max_nfts_per_address)
+            .argument(&is_public)
+            .argument(&price_overrides)
+            .original_result()
+    }
+
+    /// Phases added afterwards reuse the IDs, so their per-phase data is cleared as well 
+    pub fn clear_mint_phases<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearMintPhases")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn add_to_phase_eligibility_list<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        brand_id: Arg0,
+        phase_id: Arg1,
+        users: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addToPhaseEligibilityList")
+            .argument(&brand_id)
+            .argument(&phase_id)
+            .argument(&users)
+            .original_result()
+    }
+
+    pub fn remove_from_phase_eligibility_list<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        brand_id: Arg0,
+        phase_id: Arg1,
+        users: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFromPhaseEligibilityList")
+            .argument(&brand_id)
+            .argument(&phase_id)
+            .argument(&users)
+            .original_result()
+    }
+
+    pub fn get_active_phase_view<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue2<usize, MintPhase<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActivePhase")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn get_minted_count_for_phase<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        phase_id: Arg1,
+        address: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintedCountForPhase")
+            .argument(&brand_id)
+            .argument(&phase_id)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn mint_phases<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MintPhase<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintPhases")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn phase_eligibility_list<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        brand_id: Arg0,
+        phase_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPhaseEligibilityList")
+            .argument(&brand_id)
+            .argument(&phase_id)
+            .original_result()
+    }
+
+    pub fn set_whitelist_merkle_root<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        brand_id: Arg0,
+        merkle_root: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWhitelistMerkleRoot")
+            .argument(&brand_id)
+            .argument(&merkle_root)
+            .original_result()
+    }
+
+    pub fn clear_whitelist_merkle_root<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearWhitelistMerkleRoot")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn whitelist_merkle_root<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWhitelistMerkleRoot")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn merkle_whitelist_minted_count<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMerkleWhitelistMintedCount")
+            .argument(&brand_id)
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Once a signer is set, every buy for the brand needs a signature from it 
+    pub fn set_mint_signer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        signer: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMintSigner")
+            .argument(&brand_id)
+            .argument(&signer)
+            .original_result()
+    }
+
+    pub fn clear_mint_signer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearMintSigner")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn mint_signer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintSigner")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn nft_tiers_for_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNftTiersForBrand")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn nft_id_offset_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("nftIdOffsetForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .original_result()
+    }
+
+    pub fn is_edition_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isEditionBrand")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn is_dynamic_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isDynamicBrand")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    /// Set on the first mint of the tier's edition 
+    pub fn edition_nonce<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEditionNonce")
+            .argument(&brand_id)
+            .argument(&tier)
+            .original_result()
+    }
+
+    pub fn tier_for_nft<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        brand_id: Arg0,
+        nft_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTierForNft")
+            .argument(&brand_id)
+            .argument(&nft_nonce)
+            .original_result()
+    }
+
+    pub fn reserved_nfts<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReservedNfts")
+            .argument(&brand_id)
+            .argument(&tier)
+            .original_result()
+    }
+
+    pub fn committed_nfts<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCommittedNfts")
+            .argument(&brand_id)
+            .argument(&tier)
+            .original_result()
+    }
+
+    /// The attributes are built the same way as on mint, with the metadata of the NFT's new version 
+    pub fn update_nft_attributes<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedByteArray<Env::Api, 46usize>>,
+        Arg3: ProxyArg<usize>,
+    >(
+        self,
+        brand_id: Arg0,
+        nonce: Arg1,
+        metadata_hash: Arg2,
+        nft_id: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateNftAttributes")
+            .argument(&brand_id)
+            .argument(&nonce)
+            .argument(&metadata_hash)
+            .argument(&nft_id)
+            .original_result()
+    }
+
+    /// Replaces all the URIs of the NFT 
+    pub fn update_nft_uris<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        brand_id: Arg0,
+        nonce: Arg1,
+        uris: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateNftUris")
+            .argument(&brand_id)
+            .argument(&nonce)
+            .argument(&uris)
+            .original_result()
+    }
+
+    pub fn add_price_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+        amount: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addPriceForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_price_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+        amount: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn remove_price_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removePriceForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn set_dutch_auction_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<BigUint<Env::Api>>,
+        Arg6: ProxyArg<u64>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+        start_price: Arg3,
+        floor_price: Arg4,
+        price_decay_step: Arg5,
+        decay_interval: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDutchAuctionForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .argument(&start_price)
+            .argument(&floor_price)
+            .argument(&price_decay_step)
+            .argument(&decay_interval)
+            .original_result()
+    }
+
+    pub fn remove_dutch_auction_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeDutchAuctionForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Overrides the price for the given token while the mint whitelist is active 
+    pub fn set_presale_price_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+        amount: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPresalePriceForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn remove_presale_price_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removePresalePriceForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Once locked, prices for the brand may no longer be changed after the mint period starts. 
+    /// The lock cannot be removed. 
+    pub fn lock_prices_after_mint_start<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("lockPricesAfterMintStart")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn get_current_price_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MintPrice<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentPriceForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .original_result()
+    }
+
+    pub fn presale_price_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPresalePriceForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .original_result()
+    }
+
+    pub fn dutch_auction_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DutchAuction<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDutchAuctionForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn price_curve_for_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        tier: Arg1,
+        token_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PriceCurve<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceCurveForTier")
+            .argument(&brand_id)
+            .argument(&tier)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn prices_locked_after_mint_start<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("arePricesLockedAfterMintStart")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn set_royalties_claim_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRoyaltiesClaimAddress")
+            .argument(&new_address)
+            .original_result()
+    }
+
+    pub fn change_royalties_for_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        new_royalties: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("changeRoyaltiesForBrand")
+            .argument(&brand_id)
+            .argument(&new_royalties)
+            .original_result()
+    }
+
+    pub fn set_mint_payments_claim_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMintPaymentsClaimAddress")
+            .argument(&new_address)
+            .original_result()
+    }
+
+    /// Overrides the contract-wide mint payments claim address for the given brand 
+    pub fn set_mint_payments_claim_address_for_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        new_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMintPaymentsClaimAddressForBrand")
+            .argument(&brand_id)
+            .argument(&new_address)
+            .original_result()
+    }
+
+    /// Overrides the contract-wide royalties claim address for the given brand 
+    pub fn set_royalties_claim_address_for_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        new_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRoyaltiesClaimAddressForBrand")
+            .argument(&brand_id)
+            .argument(&new_address)
+            .original_result()
+    }
+
+    /// Splits the mint payments of the given brand between multiple recipients. 
+    /// Shares are in basis points and must total ROYALTIES_MAX. 
+    pub fn set_mint_payments_split<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, u32>>>,
+    >(
+        self,
+        brand_id: Arg0,
+        shares: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMintPaymentsSplit")
+            .argument(&brand_id)
+            .argument(&shares)
+            .original_result()
+    }
+
+    pub fn clear_mint_payments_split<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearMintPaymentsSplit")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn claim_royalties<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRoyalties")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    /// Mint payments of cancelled brands are kept for refunds 
+    pub fn claim_mint_payments<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimMintPayments")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn get_accumulated_mint_payments_per_brand(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, ManagedVec<Env::Api, MintPrice<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccumulatedMintPaymentsPerBrand")
+            .original_result()
+    }
+
+    pub fn get_accumulated_royalties_per_brand(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, ManagedVec<Env::Api, MintPrice<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccumulatedRoyaltiesPerBrand")
+            .original_result()
+    }
+
+    pub fn royalties_claim_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoyaltiesClaimAddress")
+            .original_result()
+    }
+
+    pub fn mint_payments_claim_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintPaymentsClaimAddress")
+            .original_result()
+    }
+
+    pub fn royalties_claim_address_for_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoyaltiesClaimAddressForBrand")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn mint_payments_claim_address_for_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintPaymentsClaimAddressForBrand")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn mint_payments_split<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, RevenueShare<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintPaymentsSplit")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn accumulated_royalties<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccumulatedRoyalties")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn accumulated_mint_payments<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccumulatedMintPayments")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn claim_platform_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimPlatformFees")
+            .original_result()
+    }
+
+    pub fn platform_fee_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPlatformFeeAddress")
+            .original_result()
+    }
+
+    pub fn platform_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPlatformFee")
+            .original_result()
+    }
+
+    pub fn accumulated_platform_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccumulatedPlatformFees")
+            .original_result()
+    }
+
+    pub fn cancel_brand<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelBrand")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn refund_nft<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("refundNft")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn paid_price_for_nft<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        brand_id: Arg0,
+        nft_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MintPrice<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPaidPriceForNft")
+            .argument(&brand_id)
+            .argument(&nft_nonce)
+            .original_result()
+    }
+
+    pub fn edition_purchases<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+        edition_nonce: Arg1,
+        buyer: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EditionPurchase<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEditionPurchases")
+            .argument(&brand_id)
+            .argument(&edition_nonce)
+            .argument(&buyer)
+            .original_result()
+    }
+
+    /// Share of each referred payment, in basis points, that goes to the referrer 
+    pub fn set_referral_share<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        brand_id: Arg0,
+        share: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReferralShare")
+            .argument(&brand_id)
+            .argument(&share)
+            .original_result()
+    }
+
+    pub fn claim_referral_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimReferralRewards")
+            .original_result()
+    }
+
+    pub fn referral_share<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferralShare")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn referral_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        referrer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferralRewards")
+            .argument(&referrer)
+            .original_result()
+    }
+
+    /// The marketplace only reports the claimed amounts per payment token, 
+    /// so everything claimed in one call is credited to the given brand 
+    pub fn claim_royalties_from_marketplace<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        marketplace_address: Arg0,
+        brand_id: Arg1,
+        tokens: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRoyaltiesFromMarketplace")
+            .argument(&marketplace_address)
+            .argument(&brand_id)
+            .argument(&tokens)
+            .original_result()
+    }
+
+    pub fn claim_legacy_royalties(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimLegacyRoyalties")
+            .original_result()
+    }
+
+    pub fn claim_legacy_mint_payments(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimLegacyMintPayments")
+            .original_result()
+    }
+
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStorageVersion")
+            .original_result()
+    }
+
+    pub fn legacy_accumulated_royalties(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLegacyAccumulatedRoyalties")
+            .original_result()
+    }
+
+    pub fn legacy_accumulated_mint_payments(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLegacyAccumulatedMintPayments")
+            .original_result()
+    }
+
+    pub fn get_brand_info_view<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        brand_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BrandInfoViewResultType<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBrandInfo")
+            .argument(&brand_id)
+            .original_result()
+    }
+
+    pub fn get_all_brands_info(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, BrandInfoViewResultType<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllBrandsInfo")
+            .original_result()
+    }
+
+    pub fn pause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn paused_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone, Copy)]
+pub enum BrandTokenKind {
+    Nft,
+    Edition,
+    DynamicNft,
+}
+
+#[rustfmt::skip]
+#[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
-pub struct BrandInfo<Api>
+pub enum PriceCurve<Api>
 where
     Api: ManagedTypeApi,
 {
-    pub collection_hash: ManagedByteArray<Api, 46usize>,
-    pub token_display_name: ManagedBuffer<Api>,
-    pub media_type: ManagedBuffer<Api>,
-    pub royalties: BigUint<Api>,
-    pub mint_period: TimePeriod,
-    pub whitelist_expire_timestamp: u64,
+    Linear {
+        price_increment: BigUint<Api>,
+    },
+    Stepwise {
+        step_size: usize,
+        price_increment: BigUint<Api>,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum MintProof<Api>
+where
+    Api: ManagedTypeApi,
+{
+    MerkleProof(MerkleWhitelistProof<Api>),
+    Signature(SignedMintAuthorization<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct MerkleWhitelistProof<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub quota: usize,
+    pub proof: ManagedVec<Api, ManagedByteArray<Api, 32usize>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct SignedMintAuthorization<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub expiry: u64,
+    pub nonce: u64,
+    pub signature: ManagedByteArray<Api, 64usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct PendingBuy<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub brand_id: ManagedBuffer<Api>,
+    pub tier: ManagedBuffer<Api>,
+    pub nfts_to_buy: usize,
+    pub payment: EgldOrEsdtTokenPayment<Api>,
+    pub commit_block: u64,
+    pub consumed_allowances: ConsumedAllowances,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ConsumedAllowances {
+    pub whitelist_allowance: WhitelistAllowance,
+    pub opt_phase_id: Option<usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum WhitelistAllowance {
+    None,
+    WhitelistQuota,
+    MerkleWhitelistQuota,
+    MintSignature(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct RedemptionProgram<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub input_brand_id: ManagedBuffer<Api>,
+    pub input_tier: Option<ManagedBuffer<Api>>,
+    pub nr_input_nfts: usize,
+    pub output_brand_id: ManagedBuffer<Api>,
+    pub output_tier: ManagedBuffer<Api>,
+    pub time_period: TimePeriod,
+    pub max_redemptions: usize,
 }
 
 #[type_abi]
@@ -592,6 +2176,139 @@ pub struct TimePeriod {
     pub end: u64,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct CraftingRecipe<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub inputs: ManagedVec<Api, RecipeInput<Api>>,
+    pub fee: Option<MintPrice<Api>>,
+    pub output_brand_id: ManagedBuffer<Api>,
+    pub output_tier: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug, Clone,
+)]
+pub struct RecipeInput<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub brand_id: ManagedBuffer<Api>,
+    pub tier: ManagedBuffer<Api>,
+    pub count: usize,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug, Clone,
+)]
+pub struct MintPrice<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct PlaceholderInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub tier: ManagedBuffer<Api>,
+    pub placeholder_id: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct MintPhase<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub name: ManagedBuffer<Api>,
+    pub time_period: TimePeriod,
+    pub price_overrides: ManagedVec<Api, TierPriceOverride<Api>>,
+    pub max_nfts_per_address: usize,
+    pub is_public: bool,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug, Clone,
+)]
+pub struct TierPriceOverride<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub tier: ManagedBuffer<Api>,
+    pub price: MintPrice<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct DutchAuction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub start_price: BigUint<Api>,
+    pub floor_price: BigUint<Api>,
+    pub price_decay_step: BigUint<Api>,
+    pub decay_interval: u64,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug, Clone,
+)]
+pub struct RevenueShare<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub address: ManagedAddress<Api>,
+    pub share: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct EditionPurchase<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub nr_copies: BigUint<Api>,
+    pub paid_price: MintPrice<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct BrandInfoViewResultType<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub brand_id: ManagedBuffer<Api>,
+    pub nft_token_id: EsdtTokenIdentifier<Api>,
+    pub brand_info: BrandInfo<Api>,
+    pub tier_info_entries: ArrayVec<TierInfoEntry<Api>, 5usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct BrandInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub collection_hash: ManagedByteArray<Api, 46usize>,
+    pub token_display_name: ManagedBuffer<Api>,
+    pub media_type: ManagedBuffer<Api>,
+    pub uri_base: ManagedBuffer<Api>,
+    pub royalties: BigUint<Api>,
+    pub mint_period: TimePeriod,
+    pub whitelist_expire_timestamp: u64,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug, PartialEq)]
 pub struct TierInfoEntry<Api>
@@ -601,5 +2318,7 @@ where
     pub tier: ManagedBuffer<Api>,
     pub total_nfts: usize,
     pub available_nfts: usize,
-    pub mint_price: MintPrice<Api>,
+    pub reserved_nfts: usize,
+    pub mint_prices: ManagedVec<Api, MintPrice<Api>>,
+    pub current_prices: ManagedVec<Api, MintPrice<Api>>,
 }
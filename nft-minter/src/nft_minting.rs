@@ -1,32 +1,109 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 use multiversx_sc_modules::pause;
 
 use crate::{
     brand_creation::{INVALID_BRAND_ID_ERR_MSG, INVALID_TIER_ERR_MSG},
     common_storage::{BrandId, BrandInfo, PaymentsVec},
+    delayed_reveal::PlaceholderInfo,
+    merkle_whitelist::MerkleWhitelistProof,
+    mint_phases::PhaseId,
+    mint_signature::SignedMintAuthorization,
     nft_tier::TierName,
 };
 
 const NFT_AMOUNT: u32 = 1;
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum MintProof<M: ManagedTypeApi> {
+    MerkleProof(MerkleWhitelistProof<M>),
+    Signature(SignedMintAuthorization<M>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum WhitelistAllowance {
+    None,
+    WhitelistQuota,
+    MerkleWhitelistQuota,
+    MintSignature(u64),
+}
+
+/// What a buy took from the buyer's quotas and limits, so it can be given back
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ConsumedAllowances {
+    pub whitelist_allowance: WhitelistAllowance,
+    pub opt_phase_id: Option<PhaseId>,
+}
+
 #[multiversx_sc::module]
 pub trait NftMintingModule:
     crate::common_storage::CommonStorageModule
     + crate::nft_tier::NftTierModule
+    + crate::tier_pricing::TierPricingModule
+    + crate::mint_limits::MintLimitsModule
+    + crate::mint_phases::MintPhasesModule
+    + crate::merkle_whitelist::MerkleWhitelistModule
+    + crate::mint_signature::MintSignatureModule
+    + crate::delayed_reveal::DelayedRevealModule
+    + crate::nft_updates::NftUpdatesModule
     + crate::royalties::RoyaltiesModule
+    + crate::refunds::RefundsModule
+    + crate::referrals::ReferralsModule
+    + crate::platform_fee::PlatformFeeModule
     + crate::admin_whitelist::AdminWhitelistModule
     + crate::nft_attributes_builder::NftAttributesBuilderModule
     + crate::events::EventsModule
     + pause::PauseModule
 {
+    /// Brands with a mint signer require a signature for every buy, which replaces the whitelist.
+    /// Otherwise, a Merkle proof is only needed for addresses that are not in the `mint_whitelist` set,
+    /// and only before the whitelist expires.
+    /// The platform fee is kept out of the brand's mint payments
     #[payable("*")]
     #[endpoint(buyRandomNft)]
+    #[allow_multiple_var_args]
     fn buy_random_nft(
         &self,
         brand_id: BrandId<Self::Api>,
         tier: TierName<Self::Api>,
         opt_nfts_to_buy: OptionalValue<usize>,
+        opt_mint_proof: OptionalValue<MintProof<Self::Api>>,
+    ) -> PaymentsVec<Self::Api> {
+        self.buy_random_nft_common(brand_id, tier, opt_nfts_to_buy, opt_mint_proof, None)
+    }
+
+    /// Same as `buyRandomNft`, but the referrer also gets its share of the payment
+    #[payable("*")]
+    #[endpoint(buyRandomNftWithReferrer)]
+    #[allow_multiple_var_args]
+    fn buy_random_nft_with_referrer(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        referrer: ManagedAddress,
+        opt_nfts_to_buy: OptionalValue<usize>,
+        opt_mint_proof: OptionalValue<MintProof<Self::Api>>,
+    ) -> PaymentsVec<Self::Api> {
+        self.buy_random_nft_common(
+            brand_id,
+            tier,
+            opt_nfts_to_buy,
+            opt_mint_proof,
+            Some(referrer),
+        )
+    }
+
+    fn buy_random_nft_common(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        opt_nfts_to_buy: OptionalValue<usize>,
+        opt_mint_proof: OptionalValue<MintProof<Self::Api>>,
+        opt_referrer: Option<ManagedAddress>,
     ) -> PaymentsVec<Self::Api> {
         self.require_not_paused();
         require!(
@@ -38,56 +115,20 @@ pub trait NftMintingModule:
             INVALID_TIER_ERR_MSG
         );
 
-        let nfts_to_buy = match opt_nfts_to_buy {
-            OptionalValue::Some(val) => {
-                if val == 0 {
-                    return PaymentsVec::new();
-                }
-
-                let max_nfts_per_transaction = self.max_nfts_per_transaction().get();
-                require!(
-                    val <= max_nfts_per_transaction,
-                    "Max NFTs per transaction limit exceeded"
-                );
-
-                val
-            }
-            OptionalValue::None => NFT_AMOUNT as usize,
-        };
-
-        let price_for_tier = self.price_for_tier(&brand_id, &tier).get();
-        let payment = self.call_value().egld_or_single_esdt();
-        let total_required_amount = &price_for_tier.amount * (nfts_to_buy as u32);
-        require!(
-            payment.token_identifier == price_for_tier.token_id
-                && payment.amount == total_required_amount,
-            "Invalid payment"
-        );
-
-        let brand_info: BrandInfo<Self::Api> = self.brand_info(&brand_id).get();
-        let current_timestamp = self.blockchain().get_block_timestamp();
-        require!(
-            current_timestamp >= brand_info.mint_period.start,
-            "May not mint yet"
-        );
-        require!(
-            current_timestamp < brand_info.mint_period.end,
-            "May not mint after deadline"
-        );
-
-        let caller = self.blockchain().get_caller();
-        if current_timestamp < brand_info.whitelist_expire_timestamp {
-            require!(
-                self.mint_whitelist(&brand_id).contains(&caller),
-                "Not in whitelist"
-            );
+        let nfts_to_buy = self.get_nfts_to_buy(opt_nfts_to_buy);
+        if nfts_to_buy == 0 {
+            return PaymentsVec::new();
         }
 
-        self.add_mint_payment(payment.token_identifier, payment.amount);
+        let (brand_info, _) = self.check_buy(&brand_id, &tier, nfts_to_buy, opt_mint_proof);
 
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld_or_single_esdt();
         let output_payments =
             self.mint_and_send_random_nft(&caller, &brand_id, &tier, &brand_info, nfts_to_buy);
 
+        self.distribute_mint_payment(&brand_id, &caller, &output_payments, payment, opt_referrer);
+
         self.nft_bought_event(&caller, &brand_id, &tier, nfts_to_buy);
 
         output_payments
@@ -117,6 +158,7 @@ pub trait NftMintingModule:
         for pair in dest_amount_pairs {
             let (dest_address, nfts_to_send) = pair.into_tuple();
             if nfts_to_send > 0 {
+                self.release_reserved_nfts(&brand_id, &tier, nfts_to_send);
                 let _ = self.mint_and_send_random_nft(
                     &dest_address,
                     &brand_id,
@@ -131,6 +173,201 @@ pub trait NftMintingModule:
         self.nft_giveaway_event(&brand_id, &tier, total);
     }
 
+    /// 0 means there is nothing to buy
+    fn get_nfts_to_buy(&self, opt_nfts_to_buy: OptionalValue<usize>) -> usize {
+        match opt_nfts_to_buy {
+            OptionalValue::Some(val) => {
+                if val == 0 {
+                    return 0;
+                }
+
+                let max_nfts_per_transaction = self.max_nfts_per_transaction().get();
+                require!(
+                    val <= max_nfts_per_transaction,
+                    "Max NFTs per transaction limit exceeded"
+                );
+
+                val
+            }
+            OptionalValue::None => NFT_AMOUNT as usize,
+        }
+    }
+
+    /// The referral reward is taken from what is left after the platform fee.
+    /// The brand's share is recorded with the NFTs, so it can be refunded.
+    fn distribute_mint_payment(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        buyer: &ManagedAddress,
+        output_payments: &PaymentsVec<Self::Api>,
+        payment: EgldOrEsdtTokenPayment,
+        opt_referrer: Option<ManagedAddress>,
+    ) {
+        let mut brand_amount = payment.amount;
+        brand_amount -= self.take_platform_fee(&payment.token_identifier, &brand_amount);
+        if let Some(referrer) = opt_referrer {
+            brand_amount -= self.add_referral_reward(
+                brand_id,
+                buyer,
+                &referrer,
+                &payment.token_identifier,
+                &brand_amount,
+            );
+        }
+
+        self.record_paid_prices(
+            brand_id,
+            buyer,
+            output_payments,
+            &payment.token_identifier,
+            &brand_amount,
+        );
+        self.add_mint_payment(brand_id, payment.token_identifier, brand_amount);
+    }
+
+    /// Checks the mint period, whitelist, phase and payment, and consumes the caller's quotas
+    fn check_buy(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        nfts_to_buy: usize,
+        opt_mint_proof: OptionalValue<MintProof<Self::Api>>,
+    ) -> (BrandInfo<Self::Api>, ConsumedAllowances) {
+        self.require_brand_not_cancelled(brand_id);
+
+        let brand_info: BrandInfo<Self::Api> = self.brand_info(brand_id).get();
+        let current_timestamp = self
+            .blockchain()
+            .get_block_timestamp_seconds()
+            .as_u64_seconds();
+        require!(
+            current_timestamp >= brand_info.mint_period.start,
+            "May not mint yet"
+        );
+        require!(
+            current_timestamp < brand_info.mint_period.end,
+            "May not mint after deadline"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let is_whitelist_period = current_timestamp < brand_info.whitelist_expire_timestamp;
+        let whitelist_allowance = if !self.mint_signer(brand_id).is_empty() {
+            let authorization = match opt_mint_proof {
+                OptionalValue::Some(MintProof::Signature(authorization)) => authorization,
+                _ => sc_panic!("Mint signature required"),
+            };
+            self.require_valid_mint_signature(brand_id, tier, &caller, nfts_to_buy, &authorization);
+
+            WhitelistAllowance::MintSignature(authorization.nonce)
+        } else if is_whitelist_period {
+            match opt_mint_proof {
+                OptionalValue::Some(MintProof::MerkleProof(merkle_proof)) => {
+                    self.consume_merkle_whitelist_quota(
+                        brand_id,
+                        &caller,
+                        &merkle_proof,
+                        nfts_to_buy,
+                    );
+
+                    WhitelistAllowance::MerkleWhitelistQuota
+                }
+                OptionalValue::Some(MintProof::Signature(_)) => {
+                    sc_panic!("No mint signer set for brand")
+                }
+                OptionalValue::None => {
+                    self.consume_whitelist_quota(brand_id, &caller, nfts_to_buy);
+
+                    WhitelistAllowance::WhitelistQuota
+                }
+            }
+        } else {
+            WhitelistAllowance::None
+        };
+
+        let (opt_phase_id, opt_phase_price) =
+            self.apply_mint_phase_rules(brand_id, tier, &caller, nfts_to_buy);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        let opt_presale_price = if is_whitelist_period {
+            self.presale_price_for_tier(brand_id, tier)
+                .get(&payment.token_identifier)
+        } else {
+            None
+        };
+        let opt_total_price = match (opt_phase_price, opt_presale_price) {
+            (Some(phase_price), _) => {
+                if phase_price.token_id == payment.token_identifier {
+                    Some(phase_price.amount * nfts_to_buy as u32)
+                } else {
+                    None
+                }
+            }
+            (None, Some(presale_price)) => Some(presale_price * nfts_to_buy as u32),
+            (None, None) => {
+                self.get_total_price(brand_id, tier, &payment.token_identifier, nfts_to_buy)
+            }
+        };
+        let is_valid_payment = match opt_total_price {
+            Some(total_price) => payment.amount == total_price,
+            None => false,
+        };
+        require!(is_valid_payment, "Invalid payment");
+
+        self.add_minted_nfts_for_address(brand_id, tier, &caller, nfts_to_buy);
+
+        let consumed_allowances = ConsumedAllowances {
+            whitelist_allowance,
+            opt_phase_id,
+        };
+
+        (brand_info, consumed_allowances)
+    }
+
+    fn restore_allowances(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        address: &ManagedAddress,
+        nfts_bought: usize,
+        consumed_allowances: ConsumedAllowances,
+    ) {
+        match consumed_allowances.whitelist_allowance {
+            WhitelistAllowance::None => {}
+            WhitelistAllowance::WhitelistQuota => self
+                .whitelist_quota(brand_id, address)
+                .update(|quota| *quota += nfts_bought),
+            WhitelistAllowance::MerkleWhitelistQuota => {
+                self.restore_merkle_whitelist_quota(brand_id, address, nfts_bought)
+            }
+            WhitelistAllowance::MintSignature(nonce) => {
+                self.used_mint_signature_nonces(brand_id).remove(&nonce)
+            }
+        }
+
+        if let Some(phase_id) = consumed_allowances.opt_phase_id {
+            self.remove_minted_nfts_for_phase(brand_id, phase_id, address, nfts_bought);
+        }
+
+        self.remove_minted_nfts_for_address(brand_id, tier, address, nfts_bought);
+    }
+
+    fn consume_whitelist_quota(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        address: &ManagedAddress,
+        nfts_to_buy: usize,
+    ) {
+        require!(
+            self.mint_whitelist(brand_id).contains(address),
+            "Not in whitelist"
+        );
+
+        let quota_mapper = self.whitelist_quota(brand_id, address);
+        let remaining_quota = quota_mapper.get();
+        require!(nfts_to_buy <= remaining_quota, "Whitelist quota exceeded");
+        quota_mapper.set(remaining_quota - nfts_to_buy);
+    }
+
     fn mint_and_send_random_nft(
         &self,
         to: &ManagedAddress,
@@ -144,49 +381,42 @@ pub trait NftMintingModule:
             "Only user accounts are allowed to mint"
         );
 
-        let total_available_nfts = self.available_ids(brand_id, tier).len();
+        self.require_brand_not_cancelled(brand_id);
+
+        let total_available_nfts = self.get_available_nfts(brand_id, tier);
         require!(
             nfts_to_send <= total_available_nfts,
             "Not enough NFTs available"
         );
 
-        let nft_token_id = self.nft_token(brand_id).get_token_id();
-        let mut nft_output_payments = ManagedVec::new();
-        for _ in 0..nfts_to_send {
-            let nft_id = self.get_next_random_id(brand_id, tier);
-            let nft_uri = self.build_nft_main_file_uri(
-                &brand_info.collection_hash,
-                nft_id,
-                &brand_info.media_type,
-            );
-            let nft_json = self.build_nft_json_file_uri(&brand_info.collection_hash, nft_id);
-            let collection_json = self.build_collection_json_file_uri(&brand_info.collection_hash);
-
-            let mut uris = ManagedVec::new();
-            uris.push(nft_uri);
-            uris.push(nft_json);
-            uris.push(collection_json);
-
-            let attributes =
-                self.build_nft_attributes(&brand_info.collection_hash, brand_id, nft_id);
-            let nft_amount = BigUint::from(NFT_AMOUNT);
-            let nft_name =
-                self.get_nft_name_with_tag(brand_info.token_display_name.clone(), nft_id);
-            let nft_nonce = self.send().esdt_nft_create(
-                &nft_token_id,
-                &nft_amount,
-                &nft_name,
-                &brand_info.royalties,
-                &ManagedBuffer::new(),
-                &attributes,
-                &uris,
+        let is_delayed_reveal = self.is_delayed_reveal_brand(brand_id);
+        if is_delayed_reveal {
+            require!(
+                self.reveal_block_nonce(brand_id).is_empty(),
+                "Minting closed, reveal is scheduled"
             );
+        }
+
+        let mut nft_output_payments = ManagedVec::new();
+        if self.is_edition_brand(brand_id).get() {
+            let edition_payment =
+                self.mint_edition_copies(brand_id, tier, brand_info, nfts_to_send);
+            nft_output_payments.push(edition_payment);
+        } else {
+            for _ in 0..nfts_to_send {
+                let nft_payment = if is_delayed_reveal {
+                    self.create_placeholder_nft(brand_id, tier, brand_info)
+                } else {
+                    let nft_id = self.get_next_random_id(brand_id, tier);
+                    self.create_nft(brand_id, brand_info, nft_id)
+                };
+                nft_output_payments.push(nft_payment);
+            }
+        }
 
-            nft_output_payments.push(EsdtTokenPayment::new(
-                nft_token_id.clone(),
-                nft_nonce,
-                nft_amount,
-            ));
+        for nft_payment in &nft_output_payments {
+            self.tier_for_nft(brand_id, nft_payment.token_nonce)
+                .set(tier);
         }
 
         self.send().direct_multi(to, &nft_output_payments);
@@ -194,9 +424,111 @@ pub trait NftMintingModule:
         nft_output_payments
     }
 
-    fn get_nft_name_with_tag(&self, name: ManagedBuffer, tag: usize) -> ManagedBuffer {
-        let mut nft_name = name;
-        nft_name.append(&sc_format!(" #{}", tag));
-        nft_name
+    /// The edition's nonce is created on the first mint, later mints add quantity to it
+    fn mint_edition_copies(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        brand_info: &BrandInfo<Self::Api>,
+        nr_copies: usize,
+    ) -> EsdtTokenPayment<Self::Api> {
+        self.take_edition_copies(brand_id, tier, nr_copies);
+
+        let nft_token_id = self.nft_token(brand_id).get_token_id();
+        let amount = BigUint::from(nr_copies);
+        let edition_nonce_mapper = self.edition_nonce(brand_id, tier);
+        if !edition_nonce_mapper.is_empty() {
+            let edition_nonce = edition_nonce_mapper.get();
+            self.send()
+                .esdt_local_mint(&nft_token_id, edition_nonce, &amount);
+
+            return EsdtTokenPayment::new(nft_token_id, edition_nonce, amount);
+        }
+
+        let edition_id = self.nft_id_offset_for_tier(brand_id, tier).get() + 1;
+        let uris = self.build_nft_uris(brand_info, edition_id);
+        let attributes =
+            self.build_nft_attributes(&brand_info.collection_hash, brand_id, edition_id);
+        let mut edition_name = brand_info.token_display_name.clone();
+        edition_name.append_bytes(b" ");
+        edition_name.append(tier);
+
+        let edition_nonce = self.send().esdt_nft_create(
+            &nft_token_id,
+            &amount,
+            &edition_name,
+            &brand_info.royalties,
+            &ManagedBuffer::new(),
+            &attributes,
+            &uris,
+        );
+        edition_nonce_mapper.set(edition_nonce);
+
+        EsdtTokenPayment::new(nft_token_id, edition_nonce, amount)
+    }
+
+    fn create_nft(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        brand_info: &BrandInfo<Self::Api>,
+        nft_id: UniqueId,
+    ) -> EsdtTokenPayment<Self::Api> {
+        let uris = self.build_nft_uris(brand_info, nft_id);
+        let attributes = self.build_nft_attributes(&brand_info.collection_hash, brand_id, nft_id);
+        let nft_name = self.get_nft_name_with_tag(brand_info.token_display_name.clone(), nft_id);
+
+        self.create_brand_nft(brand_id, brand_info, &nft_name, &attributes, &uris)
+    }
+
+    fn create_placeholder_nft(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        brand_info: &BrandInfo<Self::Api>,
+    ) -> EsdtTokenPayment<Self::Api> {
+        let placeholder_id = self.get_next_placeholder_id(brand_id, tier);
+
+        let mut uris = ManagedVec::new();
+        uris.push(self.placeholder_uri(brand_id).get());
+
+        // the name, attributes and URIs are set to the real NFT's on reveal
+        let nft_payment = self.create_brand_nft(
+            brand_id,
+            brand_info,
+            &brand_info.token_display_name,
+            &ManagedBuffer::new(),
+            &uris,
+        );
+
+        self.placeholder_info(brand_id, nft_payment.token_nonce)
+            .set(PlaceholderInfo {
+                tier: tier.clone(),
+                placeholder_id,
+            });
+
+        nft_payment
+    }
+
+    fn create_brand_nft(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        brand_info: &BrandInfo<Self::Api>,
+        nft_name: &ManagedBuffer,
+        attributes: &ManagedBuffer,
+        uris: &ManagedVec<ManagedBuffer>,
+    ) -> EsdtTokenPayment<Self::Api> {
+        let nft_token_id = self.nft_token(brand_id).get_token_id();
+        let nft_amount = BigUint::from(NFT_AMOUNT);
+        let nft_nonce = self.send().esdt_nft_create(
+            &nft_token_id,
+            &nft_amount,
+            nft_name,
+            &brand_info.royalties,
+            &ManagedBuffer::new(),
+            attributes,
+            uris,
+        );
+
+        EsdtTokenPayment::new(nft_token_id, nft_nonce, nft_amount)
     }
 }
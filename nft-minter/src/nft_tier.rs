@@ -1,6 +1,9 @@
 multiversx_sc::imports!();
 
-use crate::common_storage::BrandId;
+use crate::{
+    brand_creation::{INVALID_BRAND_ID_ERR_MSG, INVALID_TIER_ERR_MSG},
+    common_storage::BrandId,
+};
 
 pub type TierName<M> = ManagedBuffer<M>;
 
@@ -8,7 +11,7 @@ const VEC_MAPPER_FIRST_ITEM_INDEX: usize = 1;
 pub const MAX_TIERS_PER_BRAND: usize = 5;
 
 #[multiversx_sc::module]
-pub trait NftTierModule {
+pub trait NftTierModule: crate::common_storage::CommonStorageModule {
     fn get_next_random_id(
         &self,
         brand_id: &BrandId<Self::Api>,
@@ -25,6 +28,79 @@ pub trait NftTierModule {
         rand_id + id_offset
     }
 
+    /// Editions have no unique IDs, so the ID list only tracks the remaining supply
+    fn take_edition_copies(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        nr_copies: usize,
+    ) {
+        let mut id_mapper = self.available_ids(brand_id, tier);
+        for _ in 0..nr_copies {
+            let last_id_index = id_mapper.len();
+            require!(last_id_index > 0, "No more NFTs available for brand");
+
+            let _ = id_mapper.swap_remove(last_id_index);
+        }
+    }
+
+    fn require_valid_brand_and_tier(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) {
+        require!(
+            self.registered_brands().contains(brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(
+            self.nft_tiers_for_brand(brand_id).contains(tier),
+            INVALID_TIER_ERR_MSG
+        );
+    }
+
+    fn has_minted_nfts(&self, brand_id: &BrandId<Self::Api>) -> bool {
+        for tier in self.nft_tiers_for_brand(brand_id).iter() {
+            if self.get_nfts_minted(brand_id, &tier) > 0 {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn get_nfts_minted(&self, brand_id: &BrandId<Self::Api>, tier: &TierName<Self::Api>) -> usize {
+        let total_nfts = self.total_nfts(brand_id, tier).get();
+        let available_nfts = self.available_ids(brand_id, tier).len();
+
+        total_nfts - available_nfts
+    }
+
+    /// NFTs reserved for pending buys or for giveaways are not available
+    fn get_available_nfts(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> usize {
+        let available_ids = self.available_ids(brand_id, tier).len();
+        let committed_nfts = self.committed_nfts(brand_id, tier).get();
+        let reserved_nfts = self.reserved_nfts(brand_id, tier).get();
+
+        available_ids - committed_nfts - reserved_nfts
+    }
+
+    /// Giveaways use up the reserved NFTs first, then the ones available for sale
+    fn release_reserved_nfts(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        nfts_to_send: usize,
+    ) {
+        self.reserved_nfts(brand_id, tier).update(|reserved| {
+            *reserved -= core::cmp::min(*reserved, nfts_to_send);
+        });
+    }
+
     /// range is [min, max)
     fn get_random_usize(&self, min: usize, max: usize) -> usize {
         let mut rand_source = RandomnessSource::new();
@@ -53,6 +129,47 @@ pub trait NftTierModule {
         tier: &TierName<Self::Api>,
     ) -> UniqueIdMapper<Self::Api>;
 
+    #[view(isEditionBrand)]
+    #[storage_mapper("isEditionBrand")]
+    fn is_edition_brand(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<bool>;
+
+    #[view(isDynamicBrand)]
+    #[storage_mapper("isDynamicBrand")]
+    fn is_dynamic_brand(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<bool>;
+
+    /// Set on the first mint of the tier's edition
+    #[view(getEditionNonce)]
+    #[storage_mapper("editionNonce")]
+    fn edition_nonce(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> SingleValueMapper<u64>;
+
+    #[view(getTierForNft)]
+    #[storage_mapper("tierForNft")]
+    fn tier_for_nft(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        nft_nonce: u64,
+    ) -> SingleValueMapper<TierName<Self::Api>>;
+
+    #[view(getReservedNfts)]
+    #[storage_mapper("reservedNfts")]
+    fn reserved_nfts(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> SingleValueMapper<usize>;
+
+    #[view(getCommittedNfts)]
+    #[storage_mapper("committedNfts")]
+    fn committed_nfts(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> SingleValueMapper<usize>;
+
     #[storage_mapper("totalNfts")]
     fn total_nfts(
         &self,
@@ -0,0 +1,95 @@
+multiversx_sc::imports!();
+
+use crate::{
+    brand_creation::INVALID_BRAND_ID_ERR_MSG,
+    common_storage::BrandId,
+    nft_attributes_builder::{CollectionHash, Uri},
+};
+
+/// Dynamic NFTs keep their metadata in the system account,
+/// so the contract updates them in place through `ESDTMetaDataUpdate`, wherever the NFTs are held.
+/// Empty fields of the update are left unchanged.
+#[multiversx_sc::module]
+pub trait NftUpdatesModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+    + crate::nft_attributes_builder::NftAttributesBuilderModule
+{
+    /// The attributes are built the same way as on mint, with the metadata of the NFT's new version
+    #[endpoint(updateNftAttributes)]
+    fn update_nft_attributes(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        nonce: u64,
+        metadata_hash: CollectionHash<Self::Api>,
+        nft_id: UniqueId,
+    ) {
+        self.require_caller_is_admin();
+        self.require_dynamic_brand(&brand_id);
+
+        let attributes = self.build_nft_attributes(&metadata_hash, &brand_id, nft_id);
+        self.update_nft_metadata(
+            &brand_id,
+            nonce,
+            ManagedBuffer::new(),
+            &attributes,
+            ManagedVec::new(),
+        );
+    }
+
+    /// Replaces all the URIs of the NFT
+    #[endpoint(updateNftUris)]
+    fn update_nft_uris(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        nonce: u64,
+        uris: MultiValueEncoded<Uri<Self::Api>>,
+    ) {
+        self.require_caller_is_admin();
+        self.require_dynamic_brand(&brand_id);
+        require!(!uris.is_empty(), "No URIs provided");
+
+        self.update_nft_metadata(
+            &brand_id,
+            nonce,
+            ManagedBuffer::new(),
+            &ManagedBuffer::new(),
+            uris.to_vec(),
+        );
+    }
+
+    fn require_dynamic_brand(&self, brand_id: &BrandId<Self::Api>) {
+        require!(
+            self.registered_brands().contains(brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(self.is_dynamic_brand(brand_id).get(), "Not a dynamic brand");
+    }
+
+    fn update_nft_metadata(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        nonce: u64,
+        name: ManagedBuffer,
+        attributes: &ManagedBuffer,
+        uris: ManagedVec<Uri<Self::Api>>,
+    ) {
+        let nft_token_id = self.nft_token(brand_id).get_token_id();
+        let roles = self.blockchain().get_esdt_local_roles(&nft_token_id);
+        require!(
+            roles.has_role(&EsdtLocalRole::NftRecreate),
+            "Missing NFTRecreate role"
+        );
+
+        self.send().esdt_metadata_update(
+            nft_token_id,
+            nonce,
+            name,
+            0,
+            ManagedBuffer::new(),
+            attributes,
+            uris,
+        );
+    }
+}
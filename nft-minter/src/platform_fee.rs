@@ -0,0 +1,62 @@
+multiversx_sc::imports!();
+
+use multiversx_sc_modules::pause;
+
+use crate::{brand_creation::ROYALTIES_MAX, common_storage::EgldValuePaymentsVecPair};
+
+/// The platform fee is configured by the deployer on init and upgrade,
+/// and is kept apart from the brands' mint payments
+#[multiversx_sc::module]
+pub trait PlatformFeeModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::royalties::RoyaltiesModule
+    + pause::PauseModule
+{
+    #[endpoint(claimPlatformFees)]
+    fn claim_platform_fees(&self) -> EgldValuePaymentsVecPair<Self::Api> {
+        self.require_not_paused();
+
+        let platform_fee_address = self.platform_fee_address().get();
+        let mut mapper = self.accumulated_platform_fees();
+
+        self.claim_common(platform_fee_address, &mut mapper)
+    }
+
+    fn set_platform_fee(&self, platform_fee_address: ManagedAddress, platform_fee: u32) {
+        require!(
+            platform_fee <= ROYALTIES_MAX,
+            "Platform fee cannot be over 100%"
+        );
+
+        self.platform_fee_address().set(platform_fee_address);
+        self.platform_fee().set(platform_fee);
+    }
+
+    /// Returns the amount kept as platform fee
+    fn take_platform_fee(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        payment_amount: &BigUint,
+    ) -> BigUint {
+        let fee = payment_amount * self.platform_fee().get() / ROYALTIES_MAX;
+        if fee > 0 {
+            let mut mapper = self.accumulated_platform_fees();
+            self.add_common(&mut mapper, token.clone(), fee.clone());
+        }
+
+        fee
+    }
+
+    #[view(getPlatformFeeAddress)]
+    #[storage_mapper("platformFeeAddress")]
+    fn platform_fee_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPlatformFee)]
+    #[storage_mapper("platformFee")]
+    fn platform_fee(&self) -> SingleValueMapper<u32>;
+
+    #[view(getAccumulatedPlatformFees)]
+    #[storage_mapper("accumulatedPlatformFees")]
+    fn accumulated_platform_fees(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+}
@@ -0,0 +1,205 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc_modules::pause;
+
+use crate::{
+    brand_creation::INVALID_BRAND_ID_ERR_MSG,
+    common_storage::{BrandId, PaymentsVec, TimePeriod},
+    nft_tier::TierName,
+};
+
+pub type ProgramId = usize;
+
+/// A `max_redemptions` of 0 means there is no limit.
+/// Without an input tier, NFTs from any of the input brand's tiers are accepted.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct RedemptionProgram<M: ManagedTypeApi> {
+    pub input_brand_id: BrandId<M>,
+    pub input_tier: Option<TierName<M>>,
+    pub nr_input_nfts: usize,
+    pub output_brand_id: BrandId<M>,
+    pub output_tier: TierName<M>,
+    pub time_period: TimePeriod,
+    pub max_redemptions: usize,
+}
+
+/// Holders burn NFTs of one brand to receive a newly minted NFT of another brand's tier
+#[multiversx_sc::module]
+pub trait RedemptionModule:
+    crate::nft_minting::NftMintingModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+    + crate::tier_pricing::TierPricingModule
+    + crate::mint_limits::MintLimitsModule
+    + crate::mint_phases::MintPhasesModule
+    + crate::merkle_whitelist::MerkleWhitelistModule
+    + crate::mint_signature::MintSignatureModule
+    + crate::delayed_reveal::DelayedRevealModule
+    + crate::nft_updates::NftUpdatesModule
+    + crate::royalties::RoyaltiesModule
+    + crate::refunds::RefundsModule
+    + crate::referrals::ReferralsModule
+    + crate::platform_fee::PlatformFeeModule
+    + crate::admin_whitelist::AdminWhitelistModule
+    + crate::nft_attributes_builder::NftAttributesBuilderModule
+    + crate::events::EventsModule
+    + pause::PauseModule
+{
+    #[allow(clippy::too_many_arguments)]
+    #[endpoint(addRedemptionProgram)]
+    fn add_redemption_program(
+        &self,
+        input_brand_id: BrandId<Self::Api>,
+        nr_input_nfts: usize,
+        output_brand_id: BrandId<Self::Api>,
+        output_tier: TierName<Self::Api>,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        max_redemptions: usize,
+        opt_input_tier: OptionalValue<TierName<Self::Api>>,
+    ) -> ProgramId {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&input_brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        self.require_valid_brand_and_tier(&output_brand_id, &output_tier);
+        require!(nr_input_nfts > 0, "Must burn at least one NFT");
+        require!(start_timestamp < end_timestamp, "Invalid timestamps");
+
+        let input_tier = opt_input_tier.into_option();
+        if let Some(tier) = &input_tier {
+            self.require_valid_brand_and_tier(&input_brand_id, tier);
+        }
+
+        self.redemption_programs().push(&RedemptionProgram {
+            input_brand_id,
+            input_tier,
+            nr_input_nfts,
+            output_brand_id,
+            output_tier,
+            time_period: TimePeriod {
+                start: start_timestamp,
+                end: end_timestamp,
+            },
+            max_redemptions,
+        })
+    }
+
+    #[endpoint(setRedemptionPeriod)]
+    fn set_redemption_period(
+        &self,
+        program_id: ProgramId,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_program_id(program_id);
+        require!(start_timestamp < end_timestamp, "Invalid timestamps");
+
+        let mut program = self.redemption_programs().get(program_id);
+        program.time_period = TimePeriod {
+            start: start_timestamp,
+            end: end_timestamp,
+        };
+        self.redemption_programs().set(program_id, &program);
+    }
+
+    #[endpoint(setMaxRedemptions)]
+    fn set_max_redemptions(&self, program_id: ProgramId, max_redemptions: usize) {
+        self.require_caller_is_admin();
+        self.require_valid_program_id(program_id);
+
+        let mut program = self.redemption_programs().get(program_id);
+        program.max_redemptions = max_redemptions;
+        self.redemption_programs().set(program_id, &program);
+    }
+
+    #[payable("*")]
+    #[endpoint(redeem)]
+    fn redeem(&self, program_id: ProgramId) -> PaymentsVec<Self::Api> {
+        self.require_not_paused();
+        self.require_valid_program_id(program_id);
+
+        let program = self.redemption_programs().get(program_id);
+        let current_timestamp = self
+            .blockchain()
+            .get_block_timestamp_seconds()
+            .as_u64_seconds();
+        require!(
+            current_timestamp >= program.time_period.start
+                && current_timestamp < program.time_period.end,
+            "Redemption not active"
+        );
+
+        let redemptions_mapper = self.redemptions_count(program_id);
+        let redemptions = redemptions_mapper.get();
+        require!(
+            program.max_redemptions == 0 || redemptions < program.max_redemptions,
+            "Max redemptions reached"
+        );
+        redemptions_mapper.set(redemptions + 1);
+
+        self.burn_redemption_inputs(&program);
+
+        let caller = self.blockchain().get_caller();
+        let brand_info = self.brand_info(&program.output_brand_id).get();
+        let output_payments = self.mint_and_send_random_nft(
+            &caller,
+            &program.output_brand_id,
+            &program.output_tier,
+            &brand_info,
+            1,
+        );
+
+        self.nft_redeemed_event(&caller, program_id);
+
+        output_payments
+    }
+
+    fn burn_redemption_inputs(&self, program: &RedemptionProgram<Self::Api>) {
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        let input_token_id = self.nft_token(&program.input_brand_id).get_token_id();
+        let mut total_inputs = BigUint::zero();
+        for payment in &payments {
+            require!(
+                payment.token_identifier == input_token_id,
+                "Invalid payment"
+            );
+            if let Some(input_tier) = &program.input_tier {
+                let nft_tier = self.tier_for_nft(&program.input_brand_id, payment.token_nonce);
+                require!(&nft_tier.get() == input_tier, "Invalid NFT tier");
+            }
+
+            total_inputs += &payment.amount;
+            self.send().esdt_local_burn(
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            );
+        }
+
+        require!(
+            total_inputs == program.nr_input_nfts as u64,
+            "Invalid number of NFTs"
+        );
+    }
+
+    fn require_valid_program_id(&self, program_id: ProgramId) {
+        let programs_mapper = self.redemption_programs();
+        require!(
+            program_id > 0 && program_id <= programs_mapper.len(),
+            "Invalid program ID"
+        );
+    }
+
+    #[view(getRedemptionPrograms)]
+    #[storage_mapper("redemptionPrograms")]
+    fn redemption_programs(&self) -> VecMapper<RedemptionProgram<Self::Api>>;
+
+    #[view(getRedemptionsCount)]
+    #[storage_mapper("redemptionsCount")]
+    fn redemptions_count(&self, program_id: ProgramId) -> SingleValueMapper<usize>;
+}
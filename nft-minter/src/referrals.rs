@@ -0,0 +1,75 @@
+multiversx_sc::imports!();
+
+use multiversx_sc_modules::pause;
+
+use crate::{
+    brand_creation::{INVALID_BRAND_ID_ERR_MSG, ROYALTIES_MAX},
+    common_storage::{BrandId, EgldValuePaymentsVecPair},
+};
+
+#[multiversx_sc::module]
+pub trait ReferralsModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::royalties::RoyaltiesModule
+    + crate::events::EventsModule
+    + pause::PauseModule
+{
+    /// Share of each referred payment, in basis points, that goes to the referrer
+    #[endpoint(setReferralShare)]
+    fn set_referral_share(&self, brand_id: BrandId<Self::Api>, share: u32) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(share <= ROYALTIES_MAX, "Referral share cannot be over 100%");
+
+        self.referral_share(&brand_id).set(share);
+    }
+
+    #[endpoint(claimReferralRewards)]
+    fn claim_referral_rewards(&self) -> EgldValuePaymentsVecPair<Self::Api> {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        let mut mapper = self.referral_rewards(&caller);
+
+        self.claim_common(caller, &mut mapper)
+    }
+
+    /// Credits the referrer's share of the payment and returns it.
+    /// Buyers may not refer themselves.
+    fn add_referral_reward(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        buyer: &ManagedAddress,
+        referrer: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        payment_amount: &BigUint,
+    ) -> BigUint {
+        require!(buyer != referrer, "May not refer yourself");
+
+        let share = self.referral_share(brand_id).get();
+        let reward = payment_amount * share / ROYALTIES_MAX;
+        if reward > 0 {
+            let mut mapper = self.referral_rewards(referrer);
+            self.add_common(&mut mapper, token.clone(), reward.clone());
+        }
+
+        self.referral_event(referrer, buyer, brand_id, token, &reward);
+
+        reward
+    }
+
+    #[view(getReferralShare)]
+    #[storage_mapper("referralShare")]
+    fn referral_share(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<u32>;
+
+    #[view(getReferralRewards)]
+    #[storage_mapper("referralRewards")]
+    fn referral_rewards(
+        &self,
+        referrer: &ManagedAddress,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+}
@@ -0,0 +1,195 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc_modules::pause;
+
+use crate::{
+    brand_creation::INVALID_BRAND_ID_ERR_MSG,
+    common_storage::{BrandId, MintPrice, PaymentsVec},
+};
+
+/// Copies of an edition bought in one go, at the same price
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct EditionPurchase<M: ManagedTypeApi> {
+    pub nr_copies: BigUint<M>,
+    pub paid_price: MintPrice<M>,
+}
+
+/// Refunds give back the brand's share of the price, out of its accumulated mint payments,
+/// so payments claimed before the brand was cancelled can't be refunded.
+/// The platform fee and the referral reward are not refunded.
+#[multiversx_sc::module]
+pub trait RefundsModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+    + crate::royalties::RoyaltiesModule
+    + crate::events::EventsModule
+    + pause::PauseModule
+{
+    #[endpoint(cancelBrand)]
+    fn cancel_brand(&self, brand_id: BrandId<Self::Api>) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+
+        let cancelled_mapper = self.is_brand_cancelled(&brand_id);
+        require!(!cancelled_mapper.get(), "Brand already cancelled");
+
+        cancelled_mapper.set(true);
+
+        self.brand_cancelled_event(&brand_id);
+    }
+
+    #[payable("*")]
+    #[endpoint(refundNft)]
+    fn refund_nft(&self, brand_id: BrandId<Self::Api>) {
+        self.require_not_paused();
+        require!(
+            self.is_brand_cancelled(&brand_id).get(),
+            "Brand not cancelled"
+        );
+
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(!payments.is_empty(), "No NFTs sent");
+
+        let nft_token_id = self.nft_token(&brand_id).get_token_id();
+        let caller = self.blockchain().get_caller();
+        let is_edition_brand = self.is_edition_brand(&brand_id).get();
+        for payment in &payments {
+            require!(payment.token_identifier == nft_token_id, "Invalid payment");
+
+            if is_edition_brand {
+                self.refund_edition_copies(
+                    &brand_id,
+                    &caller,
+                    payment.token_nonce,
+                    &payment.amount,
+                );
+            } else {
+                let paid_price_mapper = self.paid_price_for_nft(&brand_id, payment.token_nonce);
+                require!(!paid_price_mapper.is_empty(), "NFT not refundable");
+
+                self.refund_paid_price(
+                    &brand_id,
+                    &caller,
+                    &paid_price_mapper.take(),
+                    &payment.amount,
+                );
+            }
+
+            self.send().esdt_local_burn(
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            );
+        }
+    }
+
+    /// Copies are refunded to the buyers only, at the price of their latest purchases first.
+    /// Copies received from others can't be refunded.
+    fn refund_edition_copies(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        buyer: &ManagedAddress,
+        edition_nonce: u64,
+        nr_copies: &BigUint,
+    ) {
+        let mut purchases_mapper = self.edition_purchases(brand_id, edition_nonce, buyer);
+        let mut remaining_copies = nr_copies.clone();
+        while remaining_copies > 0 {
+            require!(!purchases_mapper.is_empty(), "NFT not refundable");
+
+            let last_index = purchases_mapper.len();
+            let mut purchase = purchases_mapper.get(last_index);
+            let refunded_copies = if purchase.nr_copies > remaining_copies {
+                remaining_copies.clone()
+            } else {
+                purchase.nr_copies.clone()
+            };
+            self.refund_paid_price(brand_id, buyer, &purchase.paid_price, &refunded_copies);
+
+            remaining_copies -= &refunded_copies;
+            purchase.nr_copies -= &refunded_copies;
+            if purchase.nr_copies == 0 {
+                purchases_mapper.swap_remove(last_index);
+            } else {
+                purchases_mapper.set(last_index, &purchase);
+            }
+        }
+    }
+
+    fn refund_paid_price(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        buyer: &ManagedAddress,
+        paid_price: &MintPrice<Self::Api>,
+        nr_units: &BigUint,
+    ) {
+        let refund_amount = &paid_price.amount * nr_units;
+        self.remove_mint_payment(brand_id, &paid_price.token_id, &refund_amount);
+        self.send()
+            .direct(buyer, &paid_price.token_id, 0, &refund_amount);
+    }
+
+    fn require_brand_not_cancelled(&self, brand_id: &BrandId<Self::Api>) {
+        require!(!self.is_brand_cancelled(brand_id).get(), "Brand cancelled");
+    }
+
+    /// Each NFT is recorded with an equal part of the brand's share of the payment.
+    /// Edition copies share a nonce, so they are recorded per buyer and purchase instead.
+    fn record_paid_prices(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        buyer: &ManagedAddress,
+        nfts: &PaymentsVec<Self::Api>,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+        brand_amount: &BigUint,
+    ) {
+        let mut total_units = BigUint::zero();
+        for nft in nfts {
+            total_units += &nft.amount;
+        }
+
+        let unit_price = MintPrice {
+            token_id: payment_token.clone(),
+            amount: brand_amount / &total_units,
+        };
+        if self.is_edition_brand(brand_id).get() {
+            for nft in nfts {
+                self.edition_purchases(brand_id, nft.token_nonce, buyer)
+                    .push(&EditionPurchase {
+                        nr_copies: nft.amount.clone(),
+                        paid_price: unit_price.clone(),
+                    });
+            }
+
+            return;
+        }
+
+        for nft in nfts {
+            self.paid_price_for_nft(brand_id, nft.token_nonce)
+                .set(&unit_price);
+        }
+    }
+
+    #[view(getPaidPriceForNft)]
+    #[storage_mapper("paidPriceForNft")]
+    fn paid_price_for_nft(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        nft_nonce: u64,
+    ) -> SingleValueMapper<MintPrice<Self::Api>>;
+
+    #[view(getEditionPurchases)]
+    #[storage_mapper("editionPurchases")]
+    fn edition_purchases(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        edition_nonce: u64,
+        buyer: &ManagedAddress,
+    ) -> VecMapper<EditionPurchase<Self::Api>>;
+}
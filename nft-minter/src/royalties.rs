@@ -1,9 +1,19 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 use multiversx_sc_modules::pause;
 
-use crate::brand_creation::ROYALTIES_MAX;
-use crate::common_storage::{self, BrandId, EgldValuePaymentsVecPair};
+use crate::brand_creation::{INVALID_BRAND_ID_ERR_MSG, ROYALTIES_MAX};
+use crate::common_storage::{self, BrandId, EgldValuePaymentsVecPair, MintPrice};
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug, Clone,
+)]
+pub struct RevenueShare<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub share: u32,
+}
 
 #[multiversx_sc::module]
 pub trait RoyaltiesModule:
@@ -36,22 +46,173 @@ pub trait RoyaltiesModule:
         self.mint_payments_claim_address().set(&new_address);
     }
 
+    /// Overrides the contract-wide mint payments claim address for the given brand
+    #[endpoint(setMintPaymentsClaimAddressForBrand)]
+    fn set_mint_payments_claim_address_for_brand(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        new_address: ManagedAddress,
+    ) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+
+        self.mint_payments_claim_address_for_brand(&brand_id)
+            .set(&new_address);
+    }
+
+    /// Overrides the contract-wide royalties claim address for the given brand
+    #[endpoint(setRoyaltiesClaimAddressForBrand)]
+    fn set_royalties_claim_address_for_brand(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        new_address: ManagedAddress,
+    ) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+
+        self.royalties_claim_address_for_brand(&brand_id)
+            .set(&new_address);
+    }
+
+    /// Splits the mint payments of the given brand between multiple recipients.
+    /// Shares are in basis points and must total ROYALTIES_MAX.
+    #[endpoint(setMintPaymentsSplit)]
+    fn set_mint_payments_split(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        shares: MultiValueEncoded<MultiValue2<ManagedAddress, u32>>,
+    ) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(!shares.is_empty(), "No recipients provided");
+
+        let mut total_shares = 0u64;
+        let mut split = ManagedVec::new();
+        for pair in shares {
+            let (address, share) = pair.into_tuple();
+            require!(share > 0, "Invalid share");
+
+            total_shares += share as u64;
+            split.push(RevenueShare { address, share });
+        }
+        require!(
+            total_shares == ROYALTIES_MAX as u64,
+            "Shares must total 100%"
+        );
+
+        self.mint_payments_split(&brand_id).set(&split);
+    }
+
+    #[endpoint(clearMintPaymentsSplit)]
+    fn clear_mint_payments_split(&self, brand_id: BrandId<Self::Api>) {
+        self.require_caller_is_admin();
+        self.mint_payments_split(&brand_id).clear();
+    }
+
     #[endpoint(claimRoyalties)]
-    fn claim_royalties(&self) -> EgldValuePaymentsVecPair<Self::Api> {
+    fn claim_royalties(&self, brand_id: BrandId<Self::Api>) -> EgldValuePaymentsVecPair<Self::Api> {
         self.require_not_paused();
-        let royalties_claim_address = self.royalties_claim_address().get();
-        let mut mapper = self.accumulated_royalties();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+
+        let royalties_claim_address = self.get_royalties_claim_address(&brand_id);
+        let mut mapper = self.accumulated_royalties(&brand_id);
 
         self.claim_common(royalties_claim_address, &mut mapper)
     }
 
+    /// Mint payments of cancelled brands are kept for refunds
     #[endpoint(claimMintPayments)]
-    fn claim_mint_payments(&self) -> EgldValuePaymentsVecPair<Self::Api> {
+    fn claim_mint_payments(
+        &self,
+        brand_id: BrandId<Self::Api>,
+    ) -> EgldValuePaymentsVecPair<Self::Api> {
         self.require_not_paused();
-        let mint_payments_claim_address = self.mint_payments_claim_address().get();
-        let mut mapper = self.accumulated_mint_payments();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+        require!(!self.is_brand_cancelled(&brand_id).get(), "Brand cancelled");
+
+        let mint_payments_claim_address = self.get_mint_payments_claim_address(&brand_id);
+        let mut mapper = self.accumulated_mint_payments(&brand_id);
+        let split_mapper = self.mint_payments_split(&brand_id);
+        if split_mapper.is_empty() {
+            return self.claim_common(mint_payments_claim_address, &mut mapper);
+        }
+
+        self.claim_split(mint_payments_claim_address, &mut mapper, split_mapper.get())
+    }
+
+    #[view(getAccumulatedMintPaymentsPerBrand)]
+    fn get_accumulated_mint_payments_per_brand(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<BrandId<Self::Api>, ManagedVec<MintPrice<Self::Api>>>> {
+        let mut result = MultiValueEncoded::new();
+        for brand_id in self.registered_brands().iter() {
+            let payments = self.get_accumulated_amounts(&self.accumulated_mint_payments(&brand_id));
+            if !payments.is_empty() {
+                result.push((brand_id, payments).into());
+            }
+        }
+
+        result
+    }
+
+    #[view(getAccumulatedRoyaltiesPerBrand)]
+    fn get_accumulated_royalties_per_brand(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<BrandId<Self::Api>, ManagedVec<MintPrice<Self::Api>>>> {
+        let mut result = MultiValueEncoded::new();
+        for brand_id in self.registered_brands().iter() {
+            let royalties = self.get_accumulated_amounts(&self.accumulated_royalties(&brand_id));
+            if !royalties.is_empty() {
+                result.push((brand_id, royalties).into());
+            }
+        }
 
-        self.claim_common(mint_payments_claim_address, &mut mapper)
+        result
+    }
+
+    fn get_accumulated_amounts(
+        &self,
+        mapper: &MapMapper<EgldOrEsdtTokenIdentifier, BigUint>,
+    ) -> ManagedVec<MintPrice<Self::Api>> {
+        let mut amounts = ManagedVec::new();
+        for (token_id, amount) in mapper.iter() {
+            amounts.push(MintPrice { token_id, amount });
+        }
+
+        amounts
+    }
+
+    fn get_royalties_claim_address(&self, brand_id: &BrandId<Self::Api>) -> ManagedAddress {
+        let brand_claim_address_mapper = self.royalties_claim_address_for_brand(brand_id);
+        if brand_claim_address_mapper.is_empty() {
+            self.royalties_claim_address().get()
+        } else {
+            brand_claim_address_mapper.get()
+        }
+    }
+
+    fn get_mint_payments_claim_address(&self, brand_id: &BrandId<Self::Api>) -> ManagedAddress {
+        let brand_claim_address_mapper = self.mint_payments_claim_address_for_brand(brand_id);
+        if brand_claim_address_mapper.is_empty() {
+            self.mint_payments_claim_address().get()
+        } else {
+            brand_claim_address_mapper.get()
+        }
     }
 
     fn claim_common(
@@ -84,23 +245,111 @@ pub trait RoyaltiesModule:
         (egld_value, other_payments).into()
     }
 
-    fn add_mint_payment(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
-        let mut mapper = self.accumulated_mint_payments();
+    /// Any of the recipients may trigger the distribution.
+    /// Rounding leftovers go to the last recipient.
+    fn claim_split(
+        &self,
+        claim_allowed_address: ManagedAddress,
+        mapper: &mut MapMapper<EgldOrEsdtTokenIdentifier, BigUint>,
+        split: ManagedVec<RevenueShare<Self::Api>>,
+    ) -> EgldValuePaymentsVecPair<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        let is_recipient = split.iter().any(|share| share.address == caller);
+        require!(
+            caller == claim_allowed_address || is_recipient,
+            "Claim not allowed"
+        );
+
+        let last_index = split.len() - 1;
+        let mut caller_egld_value = BigUint::zero();
+        let mut caller_other_payments = ManagedVec::new();
+        for (token, total_amount) in mapper.iter() {
+            let mut remaining_amount = total_amount.clone();
+            for (i, share) in split.iter().enumerate() {
+                let amount = if i == last_index {
+                    remaining_amount.clone()
+                } else {
+                    &total_amount * share.share / ROYALTIES_MAX
+                };
+                if amount == 0 {
+                    continue;
+                }
+
+                remaining_amount -= &amount;
+
+                if share.address == caller {
+                    if token.is_egld() {
+                        caller_egld_value += &amount;
+                    } else {
+                        caller_other_payments.push(EsdtTokenPayment::new(
+                            token.clone().unwrap_esdt(),
+                            0,
+                            amount.clone(),
+                        ));
+                    }
+                }
+
+                self.send().direct(&share.address, &token, 0, &amount);
+            }
+        }
+
+        mapper.clear();
+
+        (caller_egld_value, caller_other_payments).into()
+    }
+
+    fn add_mint_payment(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let mut mapper = self.accumulated_mint_payments(brand_id);
         self.add_common(&mut mapper, token, amount);
     }
 
-    fn add_royalties(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
-        let mut mapper = self.accumulated_royalties();
+    fn remove_mint_payment(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let mut mapper = self.accumulated_mint_payments(brand_id);
+        let accumulated_amount = mapper.get(token).unwrap_or_default();
+        require!(
+            &accumulated_amount >= amount,
+            "Not enough mint payments left"
+        );
+
+        let remaining_amount = accumulated_amount - amount;
+        if remaining_amount == 0 {
+            let _ = mapper.remove(token);
+        } else {
+            let _ = mapper.insert(token.clone(), remaining_amount);
+        }
+    }
+
+    fn add_royalties(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let mut mapper = self.accumulated_royalties(brand_id);
         self.add_common(&mut mapper, token, amount);
     }
 
-    fn add_royalties_multiple(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) {
-        let mut mapper = self.accumulated_royalties();
+    fn add_royalties_multiple(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) {
+        let mut mapper = self.accumulated_royalties(brand_id);
         for p in payments {
             self.add_common(
                 &mut mapper,
-                EgldOrEsdtTokenIdentifier::esdt(p.token_identifier),
-                p.amount,
+                EgldOrEsdtTokenIdentifier::esdt(p.token_identifier.clone()),
+                p.amount.clone(),
             );
         }
     }
@@ -130,11 +379,38 @@ pub trait RoyaltiesModule:
     #[storage_mapper("mintPaymentsClaimAddress")]
     fn mint_payments_claim_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getRoyaltiesClaimAddressForBrand)]
+    #[storage_mapper("royaltiesClaimAddressForBrand")]
+    fn royalties_claim_address_for_brand(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getMintPaymentsClaimAddressForBrand)]
+    #[storage_mapper("mintPaymentsClaimAddressForBrand")]
+    fn mint_payments_claim_address_for_brand(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getMintPaymentsSplit)]
+    #[storage_mapper("mintPaymentsSplit")]
+    fn mint_payments_split(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> SingleValueMapper<ManagedVec<RevenueShare<Self::Api>>>;
+
     #[view(getAccumulatedRoyalties)]
     #[storage_mapper("accumulatedRoyalties")]
-    fn accumulated_royalties(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+    fn accumulated_royalties(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
 
     #[view(getAccumulatedMintPayments)]
     #[storage_mapper("accumulatedMintPayments")]
-    fn accumulated_mint_payments(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+    fn accumulated_mint_payments(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
 }
@@ -0,0 +1,148 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc_modules::pause;
+
+use crate::{
+    common_storage::{BrandId, BrandInfo, EgldValuePaymentsVecPair, MintPrice, TimePeriod},
+    nft_attributes_builder::{CollectionHash, MediaType, Uri},
+    nft_tier::TierName,
+};
+
+pub const CURRENT_STORAGE_VERSION: u32 = 1;
+static LEGACY_URI_BASE: &[u8] = b"https://ipfs.io/ipfs/";
+// usize is 32 bits wide in wasm
+pub const LEGACY_WHITELIST_QUOTA: usize = u32::MAX as usize;
+
+/// `BrandInfo` as stored before brands had a URI base
+#[derive(TopEncode, TopDecode)]
+pub struct LegacyBrandInfo<M: ManagedTypeApi> {
+    pub collection_hash: CollectionHash<M>,
+    pub token_display_name: ManagedBuffer<M>,
+    pub media_type: MediaType<M>,
+    pub royalties: BigUint<M>,
+    pub mint_period: TimePeriod,
+    pub whitelist_expire_timestamp: u64,
+}
+
+/// Contracts deployed before the storage was versioned are migrated on their next upgrade.
+/// New deployments start at the current version, so there is nothing to migrate.
+///
+/// Balances accumulated before they were tracked per brand can't be attributed to a brand,
+/// so they stay in the legacy pools, claimable by the contract-wide claim addresses.
+#[multiversx_sc::module]
+pub trait StorageMigrationModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+    + crate::royalties::RoyaltiesModule
+    + pause::PauseModule
+{
+    #[endpoint(claimLegacyRoyalties)]
+    fn claim_legacy_royalties(&self) -> EgldValuePaymentsVecPair<Self::Api> {
+        self.require_not_paused();
+
+        let royalties_claim_address = self.royalties_claim_address().get();
+        let mut mapper = self.legacy_accumulated_royalties();
+
+        self.claim_common(royalties_claim_address, &mut mapper)
+    }
+
+    #[endpoint(claimLegacyMintPayments)]
+    fn claim_legacy_mint_payments(&self) -> EgldValuePaymentsVecPair<Self::Api> {
+        self.require_not_paused();
+
+        let mint_payments_claim_address = self.mint_payments_claim_address().get();
+        let mut mapper = self.legacy_accumulated_mint_payments();
+
+        self.claim_common(mint_payments_claim_address, &mut mapper)
+    }
+
+    fn migrate_storage(&self) {
+        let version_mapper = self.storage_version();
+        if version_mapper.get() >= CURRENT_STORAGE_VERSION {
+            return;
+        }
+
+        for brand_id in self.registered_brands().iter() {
+            self.migrate_brand_info(&brand_id);
+            self.migrate_tier_prices(&brand_id);
+            self.migrate_whitelist_quotas(&brand_id);
+        }
+
+        version_mapper.set(CURRENT_STORAGE_VERSION);
+    }
+
+    /// Legacy brands keep the gateway that used to be hardcoded.
+    /// Entries that already decode with the current layout are left as they are.
+    fn migrate_brand_info(&self, brand_id: &BrandId<Self::Api>) {
+        let raw_brand_info = self.raw_brand_info(brand_id).get();
+        if raw_brand_info.is_empty() || BrandInfo::<Self::Api>::top_decode(raw_brand_info).is_ok() {
+            return;
+        }
+
+        let legacy_brand_info = self.legacy_brand_info(brand_id).get();
+        self.brand_info(brand_id).set(BrandInfo {
+            collection_hash: legacy_brand_info.collection_hash,
+            token_display_name: legacy_brand_info.token_display_name,
+            media_type: legacy_brand_info.media_type,
+            uri_base: Uri::new_from_bytes(LEGACY_URI_BASE),
+            royalties: legacy_brand_info.royalties,
+            mint_period: legacy_brand_info.mint_period,
+            whitelist_expire_timestamp: legacy_brand_info.whitelist_expire_timestamp,
+        });
+    }
+
+    /// Each tier used to have a single price, which becomes its only accepted price
+    fn migrate_tier_prices(&self, brand_id: &BrandId<Self::Api>) {
+        for tier in self.nft_tiers_for_brand(brand_id).iter() {
+            let legacy_price_mapper = self.legacy_price_for_tier(brand_id, &tier);
+            if legacy_price_mapper.is_empty() {
+                continue;
+            }
+
+            let legacy_price = legacy_price_mapper.take();
+            let _ = self
+                .prices_for_tier(brand_id, &tier)
+                .insert(legacy_price.token_id, legacy_price.amount);
+        }
+    }
+
+    /// Addresses whitelisted before quotas existed keep their unlimited access
+    fn migrate_whitelist_quotas(&self, brand_id: &BrandId<Self::Api>) {
+        for address in self.mint_whitelist(brand_id).iter() {
+            let quota_mapper = self.whitelist_quota(brand_id, &address);
+            if quota_mapper.is_empty() {
+                quota_mapper.set(LEGACY_WHITELIST_QUOTA);
+            }
+        }
+    }
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("brandInfo")]
+    fn raw_brand_info(&self, brand_id: &BrandId<Self::Api>) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("brandInfo")]
+    fn legacy_brand_info(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> SingleValueMapper<LegacyBrandInfo<Self::Api>>;
+
+    #[view(getLegacyAccumulatedRoyalties)]
+    #[storage_mapper("accumulatedRoyalties")]
+    fn legacy_accumulated_royalties(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    #[view(getLegacyAccumulatedMintPayments)]
+    #[storage_mapper("accumulatedMintPayments")]
+    fn legacy_accumulated_mint_payments(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    #[storage_mapper("priceForTier")]
+    fn legacy_price_for_tier(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> SingleValueMapper<MintPrice<Self::Api>>;
+}
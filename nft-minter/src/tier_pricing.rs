@@ -0,0 +1,384 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    brand_creation::INVALID_BRAND_ID_ERR_MSG,
+    common_storage::{BrandId, MintPrice},
+    nft_tier::TierName,
+};
+
+/// Price starts at `start_price` when the mint period starts,
+/// and decreases by `price_decay_step` every `decay_interval` seconds, down to `floor_price`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct DutchAuction<M: ManagedTypeApi> {
+    pub start_price: BigUint<M>,
+    pub floor_price: BigUint<M>,
+    pub price_decay_step: BigUint<M>,
+    pub decay_interval: u64,
+}
+
+/// Price increase applied on top of the tier's base price, based on the number of NFTs already minted
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum PriceCurve<M: ManagedTypeApi> {
+    Linear {
+        price_increment: BigUint<M>,
+    },
+    Stepwise {
+        step_size: usize,
+        price_increment: BigUint<M>,
+    },
+}
+
+impl<M: ManagedTypeApi> PriceCurve<M> {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            PriceCurve::Linear { .. } => true,
+            PriceCurve::Stepwise { step_size, .. } => *step_size > 0,
+        }
+    }
+
+    pub fn get_price(&self, base_price: &BigUint<M>, nfts_minted: usize) -> BigUint<M> {
+        let (nr_increments, price_increment) = match self {
+            PriceCurve::Linear { price_increment } => (nfts_minted, price_increment),
+            PriceCurve::Stepwise {
+                step_size,
+                price_increment,
+            } => (nfts_minted / step_size, price_increment),
+        };
+
+        base_price + &(price_increment * nr_increments as u64)
+    }
+}
+
+#[multiversx_sc::module]
+pub trait TierPricingModule:
+    crate::admin_whitelist::AdminWhitelistModule
+    + crate::common_storage::CommonStorageModule
+    + crate::nft_tier::NftTierModule
+    + crate::events::EventsModule
+{
+    #[endpoint(addPriceForTier)]
+    fn add_price_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+        self.require_prices_not_locked(&brand_id);
+        require!(token_id.is_valid(), "Invalid price token");
+
+        let mut mapper = self.prices_for_tier(&brand_id, &tier);
+        require!(
+            !mapper.contains_key(&token_id),
+            "Token already accepted for tier"
+        );
+
+        let _ = mapper.insert(token_id.clone(), amount.clone());
+
+        self.tier_price_changed_event(&brand_id, &tier, &token_id, &amount);
+    }
+
+    #[endpoint(setPriceForTier)]
+    fn set_price_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+        self.require_prices_not_locked(&brand_id);
+
+        let mut mapper = self.prices_for_tier(&brand_id, &tier);
+        require!(
+            mapper.contains_key(&token_id),
+            "Token not accepted for tier"
+        );
+
+        let _ = mapper.insert(token_id.clone(), amount.clone());
+
+        self.tier_price_changed_event(&brand_id, &tier, &token_id, &amount);
+    }
+
+    #[endpoint(removePriceForTier)]
+    fn remove_price_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        token_id: EgldOrEsdtTokenIdentifier,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+        self.require_prices_not_locked(&brand_id);
+
+        let mut mapper = self.prices_for_tier(&brand_id, &tier);
+        require!(
+            mapper.contains_key(&token_id),
+            "Token not accepted for tier"
+        );
+        require!(mapper.len() > 1, "Tier must have at least one price");
+
+        let _ = mapper.remove(&token_id);
+        let _ = self
+            .presale_price_for_tier(&brand_id, &tier)
+            .remove(&token_id);
+        self.dutch_auction_for_tier(&brand_id, &tier, &token_id)
+            .clear();
+        self.price_curve_for_tier(&brand_id, &tier, &token_id)
+            .clear();
+    }
+
+    #[endpoint(setDutchAuctionForTier)]
+    fn set_dutch_auction_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        token_id: EgldOrEsdtTokenIdentifier,
+        start_price: BigUint,
+        floor_price: BigUint,
+        price_decay_step: BigUint,
+        decay_interval: u64,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+        self.require_prices_not_locked(&brand_id);
+        require!(
+            self.prices_for_tier(&brand_id, &tier)
+                .contains_key(&token_id),
+            "Token not accepted for tier"
+        );
+        require!(
+            self.price_curve_for_tier(&brand_id, &tier, &token_id)
+                .is_empty(),
+            "Tier price follows a bonding curve"
+        );
+        require!(start_price >= floor_price, "Invalid auction prices");
+        require!(decay_interval > 0, "Invalid decay interval");
+
+        self.dutch_auction_for_tier(&brand_id, &tier, &token_id)
+            .set(DutchAuction {
+                start_price,
+                floor_price,
+                price_decay_step,
+                decay_interval,
+            });
+    }
+
+    #[endpoint(removeDutchAuctionForTier)]
+    fn remove_dutch_auction_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        token_id: EgldOrEsdtTokenIdentifier,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+        self.require_prices_not_locked(&brand_id);
+
+        self.dutch_auction_for_tier(&brand_id, &tier, &token_id)
+            .clear();
+    }
+
+    /// Overrides the price for the given token while the mint whitelist is active
+    #[endpoint(setPresalePriceForTier)]
+    fn set_presale_price_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+        self.require_prices_not_locked(&brand_id);
+        require!(
+            self.prices_for_tier(&brand_id, &tier)
+                .contains_key(&token_id),
+            "Token not accepted for tier"
+        );
+
+        let _ = self
+            .presale_price_for_tier(&brand_id, &tier)
+            .insert(token_id, amount);
+    }
+
+    #[endpoint(removePresalePriceForTier)]
+    fn remove_presale_price_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+        token_id: EgldOrEsdtTokenIdentifier,
+    ) {
+        self.require_caller_is_admin();
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+        self.require_prices_not_locked(&brand_id);
+
+        let _ = self
+            .presale_price_for_tier(&brand_id, &tier)
+            .remove(&token_id);
+    }
+
+    /// Once locked, prices for the brand may no longer be changed after the mint period starts.
+    /// The lock cannot be removed.
+    #[endpoint(lockPricesAfterMintStart)]
+    fn lock_prices_after_mint_start(&self, brand_id: BrandId<Self::Api>) {
+        self.require_caller_is_admin();
+        require!(
+            self.registered_brands().contains(&brand_id),
+            INVALID_BRAND_ID_ERR_MSG
+        );
+
+        self.prices_locked_after_mint_start(&brand_id).set(true);
+    }
+
+    #[view(getCurrentPriceForTier)]
+    fn get_current_price_for_tier(
+        &self,
+        brand_id: BrandId<Self::Api>,
+        tier: TierName<Self::Api>,
+    ) -> MultiValueEncoded<MintPrice<Self::Api>> {
+        self.require_valid_brand_and_tier(&brand_id, &tier);
+
+        self.get_current_prices(&brand_id, &tier).into()
+    }
+
+    fn get_current_prices(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> ManagedVec<MintPrice<Self::Api>> {
+        let mut current_prices = ManagedVec::new();
+        for token_id in self.prices_for_tier(brand_id, tier).keys() {
+            let amount = self.get_unit_price(brand_id, tier, &token_id).unwrap();
+            current_prices.push(MintPrice { token_id, amount });
+        }
+
+        current_prices
+    }
+
+    /// Price of the next NFT in the tier
+    fn get_unit_price(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> Option<BigUint> {
+        let static_price = self.prices_for_tier(brand_id, tier).get(token_id)?;
+
+        let curve_mapper = self.price_curve_for_tier(brand_id, tier, token_id);
+        if !curve_mapper.is_empty() {
+            let curve_position = self.get_curve_position(brand_id, tier);
+            return Some(curve_mapper.get().get_price(&static_price, curve_position));
+        }
+
+        let auction_mapper = self.dutch_auction_for_tier(brand_id, tier, token_id);
+        if auction_mapper.is_empty() {
+            return Some(static_price);
+        }
+
+        let auction = auction_mapper.get();
+        let mint_start = self.brand_info(brand_id).get().mint_period.start;
+        let current_timestamp = self
+            .blockchain()
+            .get_block_timestamp_seconds()
+            .as_u64_seconds();
+        let elapsed_intervals =
+            current_timestamp.saturating_sub(mint_start) / auction.decay_interval;
+        let total_decay = auction.price_decay_step * elapsed_intervals;
+        let max_decay = &auction.start_price - &auction.floor_price;
+        if total_decay >= max_decay {
+            return Some(auction.floor_price);
+        }
+
+        Some(auction.start_price - total_decay)
+    }
+
+    /// Bonding curve prices are summed for each of the bought NFTs
+    fn get_total_price(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        nfts_to_buy: usize,
+    ) -> Option<BigUint> {
+        let curve_mapper = self.price_curve_for_tier(brand_id, tier, token_id);
+        if curve_mapper.is_empty() {
+            let unit_price = self.get_unit_price(brand_id, tier, token_id)?;
+            return Some(unit_price * nfts_to_buy as u32);
+        }
+
+        let base_price = self.prices_for_tier(brand_id, tier).get(token_id)?;
+        let price_curve = curve_mapper.get();
+        let curve_position = self.get_curve_position(brand_id, tier);
+        let mut total_price = BigUint::zero();
+        for i in 0..nfts_to_buy {
+            total_price += price_curve.get_price(&base_price, curve_position + i);
+        }
+
+        Some(total_price)
+    }
+
+    /// NFTs committed to pending buys were already priced, so they count as minted
+    fn get_curve_position(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> usize {
+        self.get_nfts_minted(brand_id, tier) + self.committed_nfts(brand_id, tier).get()
+    }
+
+    fn require_prices_not_locked(&self, brand_id: &BrandId<Self::Api>) {
+        if !self.prices_locked_after_mint_start(brand_id).get() {
+            return;
+        }
+
+        let mint_start = self.brand_info(brand_id).get().mint_period.start;
+        let current_timestamp = self
+            .blockchain()
+            .get_block_timestamp_seconds()
+            .as_u64_seconds();
+        require!(
+            current_timestamp < mint_start,
+            "Prices may not be changed after mint start"
+        );
+    }
+
+    #[view(getPresalePriceForTier)]
+    #[storage_mapper("presalePriceForTier")]
+    fn presale_price_for_tier(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    #[view(getDutchAuctionForTier)]
+    #[storage_mapper("dutchAuctionForTier")]
+    fn dutch_auction_for_tier(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<DutchAuction<Self::Api>>;
+
+    #[view(getPriceCurveForTier)]
+    #[storage_mapper("priceCurveForTier")]
+    fn price_curve_for_tier(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+        tier: &TierName<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<PriceCurve<Self::Api>>;
+
+    #[view(arePricesLockedAfterMintStart)]
+    #[storage_mapper("pricesLockedAfterMintStart")]
+    fn prices_locked_after_mint_start(
+        &self,
+        brand_id: &BrandId<Self::Api>,
+    ) -> SingleValueMapper<bool>;
+}
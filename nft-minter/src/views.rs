@@ -16,18 +16,25 @@ pub struct BrandInfoViewResultType<M: ManagedTypeApi> {
     pub tier_info_entries: ArrayVec<TierInfoEntry<M>, MAX_TIERS_PER_BRAND>,
 }
 
+/// `available_nfts` are left for sale, `reserved_nfts` are left for giveaways only
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug, PartialEq)]
 pub struct TierInfoEntry<M: ManagedTypeApi> {
     pub tier: TierName<M>,
     pub total_nfts: usize,
     pub available_nfts: usize,
-    pub mint_price: MintPrice<M>,
+    pub reserved_nfts: usize,
+    pub mint_prices: ManagedVec<M, MintPrice<M>>,
+    pub current_prices: ManagedVec<M, MintPrice<M>>,
 }
 
 #[multiversx_sc::module]
 pub trait ViewsModule:
-    crate::common_storage::CommonStorageModule + crate::nft_tier::NftTierModule
+    crate::common_storage::CommonStorageModule
+    + crate::admin_whitelist::AdminWhitelistModule
+    + crate::nft_tier::NftTierModule
+    + crate::tier_pricing::TierPricingModule
+    + crate::events::EventsModule
 {
     #[view(getBrandInfo)]
     fn get_brand_info_view(
@@ -45,14 +52,21 @@ pub trait ViewsModule:
         let mut tier_info_entries = ArrayVec::new();
         for tier in self.nft_tiers_for_brand(&brand_id).iter() {
             let total_nfts = self.total_nfts(&brand_id, &tier).get();
-            let available_nfts = self.available_ids(&brand_id, &tier).len();
-            let mint_price = self.price_for_tier(&brand_id, &tier).get();
+            let available_nfts = self.get_available_nfts(&brand_id, &tier);
+            let reserved_nfts = self.reserved_nfts(&brand_id, &tier).get();
+            let mut mint_prices = ManagedVec::new();
+            for (token_id, amount) in self.prices_for_tier(&brand_id, &tier).iter() {
+                mint_prices.push(MintPrice { token_id, amount });
+            }
+            let current_prices = self.get_current_prices(&brand_id, &tier);
 
             tier_info_entries.push(TierInfoEntry {
                 tier,
                 total_nfts,
                 available_nfts,
-                mint_price,
+                reserved_nfts,
+                mint_prices,
+                current_prices,
             })
         }
 
@@ -1,8 +1,7 @@
 use nft_minter::nft_attributes_builder::COLLECTION_HASH_LEN;
 
-pub const ESDT_SYSTEM_SC_ADDRESS_ARRAY: [u8; 32] = multiversx_sc::hex_literal::hex!(
-    "000000000000000000010000000000000000000000000000000000000002ffff"
-);
+pub const ESDT_SYSTEM_SC_ADDRESS_ARRAY: [u8; 32] =
+    hex_literal::hex!("000000000000000000010000000000000000000000000000000000000002ffff");
 pub const ISSUE_COST: u64 = 50_000_000_000_000_000;
 pub const OWNER_EGLD_BALANCE: u64 = 150_000_000_000_000_000;
 pub const USER_EGLD_BALANCE: u64 = 1_000_000_000;
@@ -13,6 +12,7 @@ pub const FIRST_COLLECTION_HASH: &[u8; COLLECTION_HASH_LEN] =
     b"FirstCollection_______________________________";
 pub const FIRST_BRAND_ID: &[u8] = b"FirstBrand";
 pub const FIRST_MEDIA_TYPE: &[u8] = b"png";
+pub const FIRST_URI_BASE: &[u8] = b"https://ipfs.io/ipfs/";
 pub const FIRST_MINT_START_TIMESTAMP: u64 = 100_000_000;
 pub const FIRST_MINT_END_TIMESTAMP: u64 = 200_000_000;
 pub const FIRST_MINT_PRICE_TOKEN_ID: &[u8] = EGLD_TOKEN_ID;
@@ -28,6 +28,7 @@ pub const SECOND_COLLECTION_HASH: &[u8; COLLECTION_HASH_LEN] =
     b"SecondCollection______________________________";
 pub const SECOND_BRAND_ID: &[u8] = b"SecondBrand";
 pub const SECOND_MEDIA_TYPE: &[u8] = b"mp3";
+pub const SECOND_URI_BASE: &[u8] = b"ipfs://";
 pub const SECOND_MINT_START_TIMESTAMP: u64 = 200_000_000;
 pub const SECOND_MINT_END_TIMESTAMP: u64 = u64::MAX;
 pub const SECOND_MINT_PRICE_TOKEN_ID: &[u8] = EGLD_TOKEN_ID;
@@ -42,3 +43,41 @@ pub const SECOND_NFT_AMOUNTS: &[usize] = &[10, 15, 25];
 pub const THIRD_COLLECTION_HASH: &[u8; COLLECTION_HASH_LEN] =
     b"ThirdCollection_______________________________";
 pub const THIRD_BRAND_ID: &[u8] = b"ThirdBrand";
+pub const THIRD_TOKEN_TICKER: &[u8] = b"THIRD";
+pub const THIRD_TOKEN_ID: &[u8] = b"THIRD-7afdf5";
+pub const THIRD_TIERS: &[&[u8]] = &[b"legendary", b"common"];
+pub const THIRD_NFT_AMOUNTS: &[usize] = &[2, 10];
+pub const THIRD_TIER_PRICE_TOKEN_IDS: &[&[u8]] = &[EGLD_TOKEN_ID, STABLECOIN_TOKEN_ID];
+pub const THIRD_TIER_PRICE_AMOUNTS: &[u64] = &[5_000, 20];
+
+pub const EDITION_COLLECTION_HASH: &[u8; COLLECTION_HASH_LEN] =
+    b"EditionCollection_____________________________";
+pub const EDITION_BRAND_ID: &[u8] = b"EditionBrand";
+pub const EDITION_TOKEN_TICKER: &[u8] = b"EDITION";
+pub const EDITION_TOKEN_ID: &[u8] = b"EDITION-7afdf5";
+pub const EDITION_TIERS: &[&[u8]] = &[b"gold", b"silver"];
+pub const EDITION_AMOUNTS: &[usize] = &[3, 10];
+pub const EDITION_PRICE_AMOUNT: u64 = 500;
+
+pub const DYNAMIC_COLLECTION_HASH: &[u8; COLLECTION_HASH_LEN] =
+    b"DynamicCollection_____________________________";
+pub const DYNAMIC_EVOLVED_HASH: &[u8; COLLECTION_HASH_LEN] =
+    b"EvolvedCollection_____________________________";
+pub const DYNAMIC_BRAND_ID: &[u8] = b"DynamicBrand";
+pub const DYNAMIC_TOKEN_TICKER: &[u8] = b"DYNAMIC";
+pub const DYNAMIC_TOKEN_ID: &[u8] = b"DYNAMIC-7afdf5";
+pub const DYNAMIC_TIERS: &[&[u8]] = &[b"gold"];
+pub const DYNAMIC_NFT_AMOUNTS: &[usize] = &[5];
+
+pub const RESERVED_COLLECTION_HASH: &[u8; COLLECTION_HASH_LEN] =
+    b"ReservedCollection____________________________";
+pub const RESERVED_BRAND_ID: &[u8] = b"ReservedBrand";
+pub const RESERVED_TOKEN_TICKER: &[u8] = b"RESERVED";
+pub const RESERVED_TOKEN_ID: &[u8] = b"RESERVED-7afdf5";
+pub const RESERVED_TIER: &[u8] = b"gold";
+pub const RESERVED_TIER_TOTAL_NFTS: usize = 3;
+pub const RESERVED_TIER_RESERVED_NFTS: usize = 2;
+
+pub const STABLECOIN_TOKEN_ID: &[u8] = b"USDC-123456";
+pub const USER_STABLECOIN_BALANCE: u64 = 1_000;
+pub const STABLECOIN_PRICE_AMOUNT: u64 = 10;
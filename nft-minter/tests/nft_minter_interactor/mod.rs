@@ -1,16 +1,30 @@
 use super::constants::*;
 use multiversx_sc::{
     codec::multi_types::OptionalValue,
-    types::{Address, EsdtLocalRole, ManagedVec, MultiValueEncoded},
+    contract_base::ContractBase,
+    types::{
+        Address, EsdtLocalRole, ManagedByteArray, ManagedVec, MultiValueEncoded, TokenIdentifier,
+    },
 };
 use multiversx_sc_scenario::{
     imports::TxResult,
-    imports::{BlockchainStateWrapper, ContractObjWrapper},
+    imports::{BlockchainStateWrapper, ContractObjWrapper, TxTokenTransfer},
     managed_address, managed_biguint, managed_buffer, rust_biguint, DebugApi,
 };
-use nft_minter::brand_creation::BrandCreationModule;
+use nft_minter::brand_creation::{BrandCreationModule, BrandTokenKind};
+use nft_minter::commit_reveal_buy::CommitRevealBuyModule;
+use nft_minter::crafting::CraftingModule;
+use nft_minter::delayed_reveal::DelayedRevealModule;
+use nft_minter::merkle_whitelist::{MerkleWhitelistModule, MerkleWhitelistProof};
+use nft_minter::mint_phases::MintPhasesModule;
+use nft_minter::mint_signature::{MintSignatureModule, SignedMintAuthorization};
 use nft_minter::nft_attributes_builder::COLLECTION_HASH_LEN;
-use nft_minter::nft_minting::NftMintingModule;
+use nft_minter::nft_minting::{MintProof, NftMintingModule};
+use nft_minter::nft_tier::NftTierModule;
+use nft_minter::nft_updates::NftUpdatesModule;
+use nft_minter::redemption::RedemptionModule;
+use nft_minter::refunds::RefundsModule;
+use nft_minter::tier_pricing::{PriceCurve, TierPricingModule};
 use nft_minter::NftMinter;
 
 #[macro_export]
@@ -27,6 +41,18 @@ macro_rules! managed_token_id {
     }};
 }
 
+/// Price curves hold managed values, so they are built inside the transaction
+pub type PriceCurveBuilder = fn() -> PriceCurve<DebugApi>;
+
+/// Per-tier arguments, for tiers that don't use the brand's defaults
+#[derive(Clone, Copy, Default)]
+pub struct TierOptions {
+    pub opt_price_token_id: Option<&'static [u8]>,
+    pub opt_price_amount: Option<u64>,
+    pub opt_price_curve: Option<PriceCurveBuilder>,
+    pub reserved_nfts: usize,
+}
+
 pub struct NftMinterSetup<NftMinterObjBuilder>
 where
     NftMinterObjBuilder: 'static + Copy + Fn() -> nft_minter::ContractObj<DebugApi>,
@@ -66,6 +92,8 @@ where
                     managed_address!(&owner_address),
                     managed_address!(&owner_address),
                     MAX_NFTS_PER_TX,
+                    managed_address!(&owner_address),
+                    0,
                     OptionalValue::None,
                 );
             })
@@ -82,9 +110,11 @@ where
 
     pub fn create_default_brands(&mut self) {
         self.call_create_new_brand(
+            BrandTokenKind::Nft,
             FIRST_COLLECTION_HASH,
             FIRST_BRAND_ID,
             FIRST_MEDIA_TYPE,
+            FIRST_URI_BASE,
             0,
             FIRST_MINT_START_TIMESTAMP,
             FIRST_MINT_END_TIMESTAMP,
@@ -95,14 +125,17 @@ where
             FIRST_TAGS,
             FIRST_TIERS,
             FIRST_NFT_AMOUNTS,
+            &[],
             0,
         )
         .assert_ok();
 
         self.call_create_new_brand(
+            BrandTokenKind::Nft,
             SECOND_COLLECTION_HASH,
             SECOND_BRAND_ID,
             SECOND_MEDIA_TYPE,
+            SECOND_URI_BASE,
             0,
             SECOND_MINT_START_TIMESTAMP,
             SECOND_MINT_END_TIMESTAMP,
@@ -113,6 +146,7 @@ where
             SECOND_TAGS,
             SECOND_TIERS,
             SECOND_NFT_AMOUNTS,
+            &[],
             0,
         )
         .assert_ok();
@@ -120,7 +154,12 @@ where
         self.b_mock.set_esdt_local_roles(
             self.nm_wrapper.address_ref(),
             FIRST_TOKEN_ID,
-            &[EsdtLocalRole::NftCreate][..],
+            &[
+                EsdtLocalRole::NftCreate,
+                EsdtLocalRole::NftBurn,
+                EsdtLocalRole::NftUpdateAttributes,
+                EsdtLocalRole::NftAddUri,
+            ][..],
         );
         self.b_mock.set_esdt_local_roles(
             self.nm_wrapper.address_ref(),
@@ -129,9 +168,228 @@ where
         );
     }
 
+    /// Tiers are priced in different tokens, the first one falling back to the default token
+    pub fn create_mixed_price_brand(&mut self) {
+        self.create_mixed_price_brand_with_curves(&[None, None]);
+    }
+
+    pub fn create_mixed_price_brand_with_curves(
+        &mut self,
+        price_curves: &[Option<PriceCurveBuilder>],
+    ) {
+        let tier_options: Vec<_> = price_curves
+            .iter()
+            .enumerate()
+            .map(|(i, opt_price_curve)| TierOptions {
+                opt_price_token_id: if i == 0 {
+                    None
+                } else {
+                    Some(THIRD_TIER_PRICE_TOKEN_IDS[i])
+                },
+                opt_price_amount: Some(THIRD_TIER_PRICE_AMOUNTS[i]),
+                opt_price_curve: *opt_price_curve,
+                reserved_nfts: 0,
+            })
+            .collect();
+
+        self.call_create_new_brand(
+            BrandTokenKind::Nft,
+            THIRD_COLLECTION_HASH,
+            THIRD_BRAND_ID,
+            FIRST_MEDIA_TYPE,
+            FIRST_URI_BASE,
+            0,
+            FIRST_MINT_START_TIMESTAMP,
+            FIRST_MINT_END_TIMESTAMP,
+            THIRD_TIER_PRICE_TOKEN_IDS[0],
+            THIRD_TIER_PRICE_AMOUNTS[0],
+            THIRD_TOKEN_TICKER,
+            THIRD_TOKEN_TICKER,
+            &[],
+            THIRD_TIERS,
+            THIRD_NFT_AMOUNTS,
+            &tier_options,
+            0,
+        )
+        .assert_ok();
+
+        self.b_mock.set_esdt_local_roles(
+            self.nm_wrapper.address_ref(),
+            THIRD_TOKEN_ID,
+            &[EsdtLocalRole::NftCreate][..],
+        );
+    }
+
+    pub fn create_edition_brand(&mut self) {
+        self.call_create_new_brand(
+            BrandTokenKind::Edition,
+            EDITION_COLLECTION_HASH,
+            EDITION_BRAND_ID,
+            FIRST_MEDIA_TYPE,
+            FIRST_URI_BASE,
+            0,
+            FIRST_MINT_START_TIMESTAMP,
+            FIRST_MINT_END_TIMESTAMP,
+            EGLD_TOKEN_ID,
+            EDITION_PRICE_AMOUNT,
+            EDITION_TOKEN_TICKER,
+            EDITION_TOKEN_TICKER,
+            &[],
+            EDITION_TIERS,
+            EDITION_AMOUNTS,
+            &[],
+            0,
+        )
+        .assert_ok();
+
+        self.b_mock.set_esdt_local_roles(
+            self.nm_wrapper.address_ref(),
+            EDITION_TOKEN_ID,
+            &[
+                EsdtLocalRole::NftCreate,
+                EsdtLocalRole::NftAddQuantity,
+                EsdtLocalRole::NftBurn,
+            ][..],
+        );
+    }
+
+    /// Only given the NFTCreate role, update roles are set by the tests.
+    /// The VM's system SC can't issue dynamic tokens,
+    /// so the collection is issued as a regular one and the brand is flagged as dynamic afterwards.
+    pub fn create_dynamic_brand(&mut self) {
+        self.call_create_new_brand(
+            BrandTokenKind::Nft,
+            DYNAMIC_COLLECTION_HASH,
+            DYNAMIC_BRAND_ID,
+            FIRST_MEDIA_TYPE,
+            FIRST_URI_BASE,
+            0,
+            FIRST_MINT_START_TIMESTAMP,
+            FIRST_MINT_END_TIMESTAMP,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            DYNAMIC_TOKEN_TICKER,
+            DYNAMIC_TOKEN_TICKER,
+            &[],
+            DYNAMIC_TIERS,
+            DYNAMIC_NFT_AMOUNTS,
+            &[],
+            0,
+        )
+        .assert_ok();
+
+        self.b_mock.set_esdt_local_roles(
+            self.nm_wrapper.address_ref(),
+            DYNAMIC_TOKEN_ID,
+            &[EsdtLocalRole::NftCreate][..],
+        );
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.nm_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.is_dynamic_brand(&managed_buffer!(DYNAMIC_BRAND_ID))
+                        .set(true);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn create_reserved_supply_brand(&mut self) {
+        self.call_create_new_brand(
+            BrandTokenKind::Nft,
+            RESERVED_COLLECTION_HASH,
+            RESERVED_BRAND_ID,
+            FIRST_MEDIA_TYPE,
+            FIRST_URI_BASE,
+            0,
+            FIRST_MINT_START_TIMESTAMP,
+            FIRST_MINT_END_TIMESTAMP,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            RESERVED_TOKEN_TICKER,
+            RESERVED_TOKEN_TICKER,
+            &[],
+            &[RESERVED_TIER],
+            &[RESERVED_TIER_TOTAL_NFTS],
+            &[TierOptions {
+                reserved_nfts: RESERVED_TIER_RESERVED_NFTS,
+                ..Default::default()
+            }],
+            0,
+        )
+        .assert_ok();
+
+        self.b_mock.set_esdt_local_roles(
+            self.nm_wrapper.address_ref(),
+            RESERVED_TOKEN_ID,
+            &[EsdtLocalRole::NftCreate][..],
+        );
+    }
+
+    /// Expects the tier to accept a single payment token
+    pub fn check_current_price(&mut self, brand_id: &[u8], tier: &[u8], expected_amount: u64) {
+        self.b_mock
+            .execute_query(&self.nm_wrapper, |sc| {
+                let prices: Vec<_> = sc
+                    .get_current_price_for_tier(managed_buffer!(brand_id), managed_buffer!(tier))
+                    .to_vec()
+                    .into_iter()
+                    .collect();
+                assert_eq!(prices.len(), 1);
+                assert_eq!(prices[0].amount, managed_biguint!(expected_amount));
+            })
+            .assert_ok();
+    }
+
+    /// Dynamic NFTs keep their metadata in the system account, but the VM keeps it in the holder's account
+    /// and only applies `ESDTMetaDataUpdate` to the caller's own tokens.
+    /// A copy of the NFT in the minter's account stands in for the shared metadata.
+    pub fn set_minter_nft_copy(&mut self, token_id: &[u8], nonce: u64, uris: &[&[u8]]) {
+        let attributes =
+            self.b_mock
+                .get_nft_attributes::<Vec<u8>>(&self.first_user_address, token_id, nonce);
+        let uris: Vec<Vec<u8>> = uris.iter().map(|uri| uri.to_vec()).collect();
+        self.b_mock.set_nft_balance_all_properties(
+            self.nm_wrapper.address_ref(),
+            token_id,
+            nonce,
+            &rust_biguint!(1),
+            &attributes,
+            0,
+            None,
+            None,
+            None,
+            &uris,
+        );
+    }
+
+    pub fn check_minter_nft_metadata(
+        &mut self,
+        token_id: &[u8],
+        nonce: u64,
+        expected_attributes: &[u8],
+        expected_uris: &[&[u8]],
+    ) {
+        self.b_mock
+            .execute_query(&self.nm_wrapper, |sc| {
+                let token_data = sc.blockchain().get_esdt_token_data(
+                    &sc.blockchain().get_sc_address(),
+                    &TokenIdentifier::from_esdt_bytes(token_id),
+                    nonce,
+                );
+                assert_eq!(token_data.attributes, managed_buffer!(expected_attributes));
+
+                let uris: Vec<_> = token_data.uris.iter().map(|uri| uri.to_vec()).collect();
+                assert_eq!(uris, expected_uris);
+            })
+            .assert_ok();
+    }
+
     pub fn build_nft_attributes_first_token(&self, nft_id: usize) -> String {
         let mut attr = "metadata:".to_owned();
-        attr += &String::from_utf8(FIRST_COLLECTION_HASH.to_vec()).unwrap();
+        attr += std::str::from_utf8(FIRST_COLLECTION_HASH).unwrap();
         attr += "/";
         attr += &nft_id.to_string();
         attr += ".json;";
@@ -140,9 +398,31 @@ where
         attr
     }
 
+    pub fn build_nft_attributes_dynamic_token(&self, nft_id: usize) -> String {
+        let mut attr = "metadata:".to_owned();
+        attr += std::str::from_utf8(DYNAMIC_COLLECTION_HASH).unwrap();
+        attr += "/";
+        attr += &nft_id.to_string();
+        attr += ".json";
+
+        attr
+    }
+
+    pub fn build_nft_uris_dynamic_token(&self, nft_id: usize) -> Vec<String> {
+        let mut collection_uri = std::str::from_utf8(FIRST_URI_BASE).unwrap().to_owned();
+        collection_uri += std::str::from_utf8(DYNAMIC_COLLECTION_HASH).unwrap();
+        collection_uri += "/";
+
+        vec![
+            format!("{collection_uri}{nft_id}.png"),
+            format!("{collection_uri}{nft_id}.json"),
+            format!("{collection_uri}collection.json"),
+        ]
+    }
+
     pub fn build_nft_attributes_second_token(&self, nft_id: usize) -> String {
         let mut attr = "metadata:".to_owned();
-        attr += &String::from_utf8(SECOND_COLLECTION_HASH.to_vec()).unwrap();
+        attr += std::str::from_utf8(SECOND_COLLECTION_HASH).unwrap();
         attr += "/";
         attr += &nft_id.to_string();
         attr += ".json;";
@@ -156,11 +436,15 @@ impl<NftMinterObjBuilder> NftMinterSetup<NftMinterObjBuilder>
 where
     NftMinterObjBuilder: 'static + Copy + Fn() -> nft_minter::ContractObj<DebugApi>,
 {
+    /// `tier_options` is either empty, for tiers with the brand's defaults, or has an entry per tier
+    #[allow(clippy::too_many_arguments)]
     pub fn call_create_new_brand(
         &mut self,
+        token_kind: BrandTokenKind,
         collection_hash: &[u8; COLLECTION_HASH_LEN],
         brand_id: &[u8],
         media_type: &[u8],
+        uri_base: &[u8],
         royalties: u64,
         mint_start_timestamp: u64,
         mint_end_timestamp: u64,
@@ -171,6 +455,7 @@ where
         tags: &[&[u8]],
         tiers: &[&[u8]],
         nr_nfts_per_tier: &[usize],
+        tier_options: &[TierOptions],
         whitelist_expire_epoch: u64,
     ) -> TxResult {
         self.b_mock.execute_tx(
@@ -183,17 +468,25 @@ where
                     managed_tags.push(managed_buffer!(&tag));
                 }
 
-                if tiers.len() != nr_nfts_per_tier.len() {
+                if tiers.len() != nr_nfts_per_tier.len()
+                    || (!tier_options.is_empty() && tiers.len() != tier_options.len())
+                {
                     panic!("Tier args length mismatch");
                 }
 
                 let mut tier_args = MultiValueEncoded::new();
-                for (tier, nr_nfts) in tiers.iter().zip(nr_nfts_per_tier.iter()) {
+                for (i, (tier, nr_nfts)) in tiers.iter().zip(nr_nfts_per_tier.iter()).enumerate() {
+                    let options = tier_options.get(i).copied().unwrap_or_default();
                     tier_args.push(
                         (
                             managed_buffer!(tier),
                             *nr_nfts,
-                            managed_biguint!(mint_price_amount),
+                            managed_biguint!(options.opt_price_amount.unwrap_or(mint_price_amount)),
+                            options
+                                .opt_price_token_id
+                                .map(|token_id| managed_token_id!(token_id)),
+                            options.opt_price_curve.map(|price_curve| price_curve()),
+                            options.reserved_nfts,
                         )
                             .into(),
                     );
@@ -203,6 +496,7 @@ where
                     collection_hash.into(),
                     managed_buffer!(brand_id),
                     managed_buffer!(media_type),
+                    managed_buffer!(uri_base),
                     managed_biguint!(royalties),
                     mint_start_timestamp,
                     mint_end_timestamp,
@@ -211,6 +505,7 @@ where
                     managed_buffer!(token_ticker),
                     whitelist_expire_epoch,
                     managed_tags,
+                    token_kind,
                     tier_args,
                 );
             },
@@ -242,6 +537,7 @@ where
                         managed_buffer!(brand_id),
                         managed_buffer!(tier),
                         opt_nft_amount,
+                        OptionalValue::None,
                     );
                 },
             )
@@ -257,12 +553,547 @@ where
                         managed_buffer!(brand_id),
                         managed_buffer!(tier),
                         opt_nft_amount,
+                        OptionalValue::None,
                     );
                 },
             )
         }
     }
 
+    pub fn call_commit_buy(
+        &mut self,
+        buyer_address: &Address,
+        payment_amount: u64,
+        brand_id: &[u8],
+        tier: &[u8],
+        nfts_to_buy: usize,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            buyer_address,
+            &self.nm_wrapper,
+            &rust_biguint!(payment_amount),
+            |sc| {
+                sc.commit_buy(
+                    managed_buffer!(brand_id),
+                    managed_buffer!(tier),
+                    OptionalValue::Some(nfts_to_buy),
+                    OptionalValue::None,
+                );
+            },
+        )
+    }
+
+    pub fn call_reveal_buy(&mut self, buyer_address: &Address) -> TxResult {
+        self.b_mock
+            .execute_tx(buyer_address, &self.nm_wrapper, &rust_biguint!(0), |sc| {
+                let _ = sc.reveal_buy();
+            })
+    }
+
+    pub fn call_refund_expired_buy(&mut self, buyer_address: &Address) -> TxResult {
+        self.b_mock
+            .execute_tx(buyer_address, &self.nm_wrapper, &rust_biguint!(0), |sc| {
+                sc.refund_expired_buy();
+            })
+    }
+
+    pub fn call_buy_random_nft_with_referrer(
+        &mut self,
+        buyer_address: &Address,
+        payment_amount: u64,
+        brand_id: &[u8],
+        tier: &[u8],
+        referrer_address: &Address,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            buyer_address,
+            &self.nm_wrapper,
+            &rust_biguint!(payment_amount),
+            |sc| {
+                sc.buy_random_nft_with_referrer(
+                    managed_buffer!(brand_id),
+                    managed_buffer!(tier),
+                    managed_address!(referrer_address),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn call_buy_random_nft_with_merkle_proof(
+        &mut self,
+        buyer_address: &Address,
+        payment_amount: u64,
+        brand_id: &[u8],
+        tier: &[u8],
+        nfts_to_buy: usize,
+        quota: usize,
+        proof: &[[u8; 32]],
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            buyer_address,
+            &self.nm_wrapper,
+            &rust_biguint!(payment_amount),
+            |sc| {
+                let mut managed_proof = ManagedVec::new();
+                for proof_element in proof {
+                    managed_proof.push(ManagedByteArray::new_from_bytes(proof_element));
+                }
+
+                sc.buy_random_nft(
+                    managed_buffer!(brand_id),
+                    managed_buffer!(tier),
+                    OptionalValue::Some(nfts_to_buy),
+                    OptionalValue::Some(MintProof::MerkleProof(MerkleWhitelistProof {
+                        quota,
+                        proof: managed_proof,
+                    })),
+                );
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn call_buy_random_nft_with_signature(
+        &mut self,
+        buyer_address: &Address,
+        payment_amount: u64,
+        brand_id: &[u8],
+        tier: &[u8],
+        nfts_to_buy: usize,
+        expiry: u64,
+        nonce: u64,
+        signature: &[u8; 64],
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            buyer_address,
+            &self.nm_wrapper,
+            &rust_biguint!(payment_amount),
+            |sc| {
+                sc.buy_random_nft(
+                    managed_buffer!(brand_id),
+                    managed_buffer!(tier),
+                    OptionalValue::Some(nfts_to_buy),
+                    OptionalValue::Some(MintProof::Signature(SignedMintAuthorization {
+                        expiry,
+                        nonce,
+                        signature: ManagedByteArray::new_from_bytes(signature),
+                    })),
+                );
+            },
+        )
+    }
+
+    pub fn call_set_mint_signer(&mut self, brand_id: &[u8], signer: &Address) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_mint_signer(managed_buffer!(brand_id), managed_address!(signer));
+            },
+        )
+    }
+
+    /// Builds the message off-chain, the way a signing service would
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_mint_signature_message(
+        &self,
+        minter_address: &Address,
+        buyer_address: &Address,
+        brand_id: &[u8],
+        tier: &[u8],
+        nfts_to_buy: usize,
+        expiry: u64,
+        nonce: u64,
+    ) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.extend_from_slice(minter_address.as_bytes());
+        message.extend_from_slice(buyer_address.as_bytes());
+        for buffer in [brand_id, tier] {
+            message.extend_from_slice(&(buffer.len() as u32).to_be_bytes());
+            message.extend_from_slice(buffer);
+        }
+        message.extend_from_slice(&(nfts_to_buy as u64).to_be_bytes());
+        message.extend_from_slice(&expiry.to_be_bytes());
+        message.extend_from_slice(&nonce.to_be_bytes());
+
+        message
+    }
+
+    pub fn call_set_whitelist_merkle_root(&mut self, brand_id: &[u8], root: &[u8; 32]) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_whitelist_merkle_root(
+                    managed_buffer!(brand_id),
+                    ManagedByteArray::new_from_bytes(root),
+                );
+            },
+        )
+    }
+
+    /// Builds the leaf and pair hashes with the contract's own hashing helpers
+    pub fn compute_merkle_leaf(&mut self, address: &Address, quota: usize) -> [u8; 32] {
+        let mut leaf = [0u8; 32];
+        self.b_mock
+            .execute_query(&self.nm_wrapper, |sc| {
+                leaf = sc
+                    .compute_merkle_leaf(&managed_address!(address), quota)
+                    .to_byte_array();
+            })
+            .assert_ok();
+
+        leaf
+    }
+
+    pub fn hash_merkle_pair(&mut self, first: &[u8; 32], second: &[u8; 32]) -> [u8; 32] {
+        let mut hash = [0u8; 32];
+        self.b_mock
+            .execute_query(&self.nm_wrapper, |sc| {
+                hash = sc
+                    .hash_merkle_pair(
+                        &ManagedByteArray::new_from_bytes(first),
+                        &ManagedByteArray::new_from_bytes(second),
+                    )
+                    .to_byte_array();
+            })
+            .assert_ok();
+
+        hash
+    }
+
+    pub fn call_add_price_for_tier(
+        &mut self,
+        brand_id: &[u8],
+        tier: &[u8],
+        token_id: &[u8],
+        amount: u64,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_price_for_tier(
+                    managed_buffer!(brand_id),
+                    managed_buffer!(tier),
+                    managed_token_id!(token_id),
+                    managed_biguint!(amount),
+                );
+            },
+        )
+    }
+
+    pub fn call_set_price_for_tier(
+        &mut self,
+        brand_id: &[u8],
+        tier: &[u8],
+        token_id: &[u8],
+        amount: u64,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_price_for_tier(
+                    managed_buffer!(brand_id),
+                    managed_buffer!(tier),
+                    managed_token_id!(token_id),
+                    managed_biguint!(amount),
+                );
+            },
+        )
+    }
+
+    pub fn call_set_mint_period(
+        &mut self,
+        brand_id: &[u8],
+        mint_start_timestamp: u64,
+        mint_end_timestamp: u64,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_mint_period(
+                    managed_buffer!(brand_id),
+                    mint_start_timestamp,
+                    mint_end_timestamp,
+                );
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn call_add_mint_phase(
+        &mut self,
+        brand_id: &[u8],
+        name: &[u8],
+        start_timestamp: u64,
+        end_timestamp: u64,
+        max_nfts_per_address: usize,
+        is_public: bool,
+        price_overrides: &[(&[u8], &[u8], u64)],
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut price_override_args = MultiValueEncoded::new();
+                for (tier, token_id, amount) in price_overrides {
+                    price_override_args.push(
+                        (
+                            managed_buffer!(tier),
+                            managed_token_id!(*token_id),
+                            managed_biguint!(*amount),
+                        )
+                            .into(),
+                    );
+                }
+
+                let _ = sc.add_mint_phase(
+                    managed_buffer!(brand_id),
+                    managed_buffer!(name),
+                    start_timestamp,
+                    end_timestamp,
+                    max_nfts_per_address,
+                    is_public,
+                    price_override_args,
+                );
+            },
+        )
+    }
+
+    pub fn call_enable_delayed_reveal(
+        &mut self,
+        brand_id: &[u8],
+        placeholder_uri: &[u8],
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.enable_delayed_reveal(
+                    managed_buffer!(brand_id),
+                    managed_buffer!(placeholder_uri),
+                );
+            },
+        )
+    }
+
+    pub fn call_schedule_reveal(&mut self, brand_id: &[u8]) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.schedule_reveal(managed_buffer!(brand_id));
+            },
+        )
+    }
+
+    pub fn call_reveal(&mut self, brand_id: &[u8]) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.reveal(managed_buffer!(brand_id));
+            },
+        )
+    }
+
+    pub fn call_reveal_nfts(&mut self, brand_id: &[u8], placeholder_nonces: &[u64]) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut managed_nonces = MultiValueEncoded::new();
+                for nonce in placeholder_nonces {
+                    managed_nonces.push(*nonce);
+                }
+
+                sc.reveal_nfts(managed_buffer!(brand_id), managed_nonces);
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn call_add_redemption_program(
+        &mut self,
+        input_brand_id: &[u8],
+        opt_input_tier: Option<&[u8]>,
+        nr_input_nfts: usize,
+        output_brand_id: &[u8],
+        output_tier: &[u8],
+        start_timestamp: u64,
+        end_timestamp: u64,
+        max_redemptions: usize,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.add_redemption_program(
+                    managed_buffer!(input_brand_id),
+                    nr_input_nfts,
+                    managed_buffer!(output_brand_id),
+                    managed_buffer!(output_tier),
+                    start_timestamp,
+                    end_timestamp,
+                    max_redemptions,
+                    opt_input_tier.map(|tier| managed_buffer!(tier)).into(),
+                );
+            },
+        )
+    }
+
+    pub fn call_redeem(
+        &mut self,
+        caller: &Address,
+        program_id: usize,
+        token_id: &[u8],
+        nonces: &[u64],
+    ) -> TxResult {
+        let transfers = build_nft_transfers(token_id, nonces);
+        self.b_mock
+            .execute_esdt_multi_transfer(caller, &self.nm_wrapper, &transfers, |sc| {
+                let _ = sc.redeem(program_id);
+            })
+    }
+
+    pub fn call_add_crafting_recipe(
+        &mut self,
+        output_brand_id: &[u8],
+        output_tier: &[u8],
+        fee_token_id: &[u8],
+        fee_amount: u64,
+        inputs: &[(&[u8], &[u8], usize)],
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut managed_inputs = MultiValueEncoded::new();
+                for (brand_id, tier, count) in inputs {
+                    managed_inputs
+                        .push((managed_buffer!(brand_id), managed_buffer!(tier), *count).into());
+                }
+
+                let _ = sc.add_crafting_recipe(
+                    managed_buffer!(output_brand_id),
+                    managed_buffer!(output_tier),
+                    managed_token_id!(fee_token_id),
+                    managed_biguint!(fee_amount),
+                    managed_inputs,
+                );
+            },
+        )
+    }
+
+    pub fn call_craft(
+        &mut self,
+        caller: &Address,
+        recipe_id: usize,
+        token_id: &[u8],
+        nonces: &[u64],
+        fee: Option<(&[u8], u64)>,
+    ) -> TxResult {
+        let mut transfers = build_nft_transfers(token_id, nonces);
+        if let Some((fee_token_id, fee_amount)) = fee {
+            transfers.push(TxTokenTransfer {
+                token_identifier: fee_token_id.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(fee_amount),
+            });
+        }
+
+        self.b_mock
+            .execute_esdt_multi_transfer(caller, &self.nm_wrapper, &transfers, |sc| {
+                let _ = sc.craft(recipe_id);
+            })
+    }
+
+    pub fn call_cancel_brand(&mut self, brand_id: &[u8]) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.cancel_brand(managed_buffer!(brand_id));
+            },
+        )
+    }
+
+    pub fn call_refund_nfts(
+        &mut self,
+        caller: &Address,
+        brand_id: &[u8],
+        token_id: &[u8],
+        nonces: &[u64],
+    ) -> TxResult {
+        let transfers = build_nft_transfers(token_id, nonces);
+        self.b_mock
+            .execute_esdt_multi_transfer(caller, &self.nm_wrapper, &transfers, |sc| {
+                sc.refund_nft(managed_buffer!(brand_id));
+            })
+    }
+
+    pub fn call_update_nft_attributes(
+        &mut self,
+        brand_id: &[u8],
+        nonce: u64,
+        metadata_hash: &[u8; COLLECTION_HASH_LEN],
+        nft_id: usize,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.update_nft_attributes(
+                    managed_buffer!(brand_id),
+                    nonce,
+                    metadata_hash.into(),
+                    nft_id,
+                );
+            },
+        )
+    }
+
+    pub fn call_update_nft_uris(
+        &mut self,
+        brand_id: &[u8],
+        nonce: u64,
+        uris: &[&[u8]],
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut managed_uris = MultiValueEncoded::new();
+                for uri in uris {
+                    managed_uris.push(managed_buffer!(uri));
+                }
+
+                sc.update_nft_uris(managed_buffer!(brand_id), nonce, managed_uris);
+            },
+        )
+    }
+
     pub fn call_giveaway(
         &mut self,
         brand_id: &[u8],
@@ -284,3 +1115,14 @@ where
         )
     }
 }
+
+fn build_nft_transfers(token_id: &[u8], nonces: &[u64]) -> Vec<TxTokenTransfer> {
+    nonces
+        .iter()
+        .map(|nonce| TxTokenTransfer {
+            token_identifier: token_id.to_vec(),
+            nonce: *nonce,
+            value: rust_biguint!(1),
+        })
+        .collect()
+}
@@ -2,15 +2,34 @@ pub mod constants;
 pub mod nft_minter_interactor;
 
 use constants::*;
-use multiversx_sc::types::{ManagedBuffer, ManagedByteArray, MultiValueEncoded};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{
+    Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedBuffer, ManagedByteArray, ManagedVec,
+    MultiValueEncoded, TimestampSeconds,
+};
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_buffer, rust_biguint, DebugApi,
 };
-use nft_minter::brand_creation::BrandCreationModule;
-use nft_minter::common_storage::{BrandInfo, MintPrice, TimePeriod};
+use nft_marketplace_mock::NftMarketplaceMock;
+use nft_minter::brand_creation::{BrandCreationModule, BrandTokenKind};
+use nft_minter::commit_reveal_buy::COMMIT_EXPIRY_BLOCKS;
+use nft_minter::common_storage::{BrandInfo, CommonStorageModule, MintPrice, TimePeriod};
+use nft_minter::delayed_reveal::{DelayedRevealModule, REVEAL_BLOCK_DELAY};
+use nft_minter::merkle_whitelist::MerkleWhitelistModule;
+use nft_minter::mint_limits::MintLimitsModule;
+use nft_minter::mint_phases::MintPhasesModule;
 use nft_minter::nft_attributes_builder::{NftAttributesBuilderModule, COLLECTION_HASH_LEN};
+use nft_minter::nft_marketplace_interactor::NftMarketplaceInteractorModule;
 use nft_minter::nft_tier::NftTierModule;
+use nft_minter::platform_fee::PlatformFeeModule;
+use nft_minter::redemption::RedemptionModule;
+use nft_minter::referrals::ReferralsModule;
 use nft_minter::royalties::RoyaltiesModule;
+use nft_minter::storage_migration::{
+    LegacyBrandInfo, StorageMigrationModule, CURRENT_STORAGE_VERSION, LEGACY_WHITELIST_QUOTA,
+};
+use nft_minter::tier_pricing::{DutchAuction, PriceCurve, TierPricingModule};
 use nft_minter::views::{TierInfoEntry, ViewsModule};
 use nft_minter::NftMinter;
 use nft_minter_interactor::*;
@@ -28,9 +47,11 @@ fn create_brands_test() {
     // try create brand, same collection
     nm_setup
         .call_create_new_brand(
+            BrandTokenKind::Nft,
             FIRST_COLLECTION_HASH,
             THIRD_BRAND_ID,
             b"png",
+            b"https://ipfs.io/ipfs/",
             0,
             1,
             2,
@@ -41,6 +62,7 @@ fn create_brands_test() {
             &[],
             FIRST_TIERS,
             FIRST_NFT_AMOUNTS,
+            &[],
             0,
         )
         .assert_user_error("Collection hash already exists");
@@ -48,9 +70,11 @@ fn create_brands_test() {
     // try create brand, same brand ID
     nm_setup
         .call_create_new_brand(
+            BrandTokenKind::Nft,
             THIRD_COLLECTION_HASH,
             FIRST_BRAND_ID,
             b"png",
+            b"https://ipfs.io/ipfs/",
             0,
             1,
             2,
@@ -61,6 +85,7 @@ fn create_brands_test() {
             &[],
             FIRST_TIERS,
             FIRST_NFT_AMOUNTS,
+            &[],
             0,
         )
         .assert_user_error("Brand already exists");
@@ -68,9 +93,11 @@ fn create_brands_test() {
     // try create brand, unsupported media type
     nm_setup
         .call_create_new_brand(
+            BrandTokenKind::Nft,
             THIRD_COLLECTION_HASH,
             THIRD_BRAND_ID,
             b"exe",
+            b"https://ipfs.io/ipfs/",
             0,
             1,
             2,
@@ -81,10 +108,34 @@ fn create_brands_test() {
             &[],
             FIRST_TIERS,
             FIRST_NFT_AMOUNTS,
+            &[],
             0,
         )
         .assert_user_error("Invalid media type");
 
+    // try create brand, tier without NFTs
+    nm_setup
+        .call_create_new_brand(
+            BrandTokenKind::Nft,
+            THIRD_COLLECTION_HASH,
+            THIRD_BRAND_ID,
+            b"png",
+            b"https://ipfs.io/ipfs/",
+            0,
+            1,
+            2,
+            b"EGLD",
+            1,
+            b"",
+            b"TICKER",
+            &[],
+            FIRST_TIERS,
+            &[FIRST_NFT_AMOUNTS[0], 0, FIRST_NFT_AMOUNTS[2]],
+            &[],
+            0,
+        )
+        .assert_user_error("Tier must have at least one NFT");
+
     // get brand by id
     nm_setup
         .b_mock
@@ -103,6 +154,7 @@ fn create_brands_test() {
                 ),
                 token_display_name: managed_buffer!(FIRST_TOKEN_DISPLAY_NAME),
                 media_type: managed_buffer!(FIRST_MEDIA_TYPE),
+                uri_base: managed_buffer!(FIRST_URI_BASE),
                 royalties: managed_biguint!(0),
                 mint_period: TimePeriod {
                     start: FIRST_MINT_START_TIMESTAMP,
@@ -117,11 +169,16 @@ fn create_brands_test() {
                 expected_tier_info.push(TierInfoEntry::<DebugApi> {
                     tier: managed_buffer!(tier),
                     available_nfts: *nft_amount,
+                    reserved_nfts: 0,
                     total_nfts: *nft_amount,
-                    mint_price: MintPrice::<DebugApi> {
+                    mint_prices: ManagedVec::from_single_item(MintPrice::<DebugApi> {
                         token_id: managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID),
                         amount: managed_biguint!(FIRST_MINT_PRICE_AMOUNT),
-                    },
+                    }),
+                    current_prices: ManagedVec::from_single_item(MintPrice::<DebugApi> {
+                        token_id: managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID),
+                        amount: managed_biguint!(FIRST_MINT_PRICE_AMOUNT),
+                    }),
                 });
             }
             assert_eq!(
@@ -164,7 +221,7 @@ fn buy_random_nft_test() {
 
     nm_setup
         .b_mock
-        .set_block_timestamp(FIRST_MINT_START_TIMESTAMP);
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
 
     // buy random nft ok
     let first_user_addr = nm_setup.first_user_address.clone();
@@ -308,7 +365,7 @@ fn buy_random_nft_test() {
     nm_setup
         .b_mock
         .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
-            let result = sc.claim_mint_payments();
+            let result = sc.claim_mint_payments(managed_buffer!(FIRST_BRAND_ID));
             let (egld_amt, other_payments) = result.into_tuple();
 
             assert_eq!(egld_amt, managed_biguint!(3 * FIRST_MINT_PRICE_AMOUNT));
@@ -325,7 +382,7 @@ fn buy_random_nft_test() {
     // try buy after deadline
     nm_setup
         .b_mock
-        .set_block_timestamp(FIRST_MINT_END_TIMESTAMP);
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_END_TIMESTAMP));
 
     nm_setup
         .call_buy_random_nft(
@@ -340,230 +397,3138 @@ fn buy_random_nft_test() {
 }
 
 #[test]
-fn buy_whitelist_test() {
+fn mixed_price_tokens_test() {
     let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
-    let first_tier = FIRST_TIERS[0];
-    let first_user_addr = nm_setup.first_user_address.clone();
+    nm_setup.create_mixed_price_brand();
 
-    nm_setup.create_default_brands();
     nm_setup
         .b_mock
-        .set_block_timestamp(FIRST_MINT_START_TIMESTAMP);
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let result = sc.get_brand_info_view(managed_buffer!(THIRD_BRAND_ID));
+
+            let mut expected_tier_info = Vec::new();
+            for (i, tier) in THIRD_TIERS.iter().enumerate() {
+                expected_tier_info.push(TierInfoEntry::<DebugApi> {
+                    tier: managed_buffer!(tier),
+                    available_nfts: THIRD_NFT_AMOUNTS[i],
+                    reserved_nfts: 0,
+                    total_nfts: THIRD_NFT_AMOUNTS[i],
+                    mint_prices: ManagedVec::from_single_item(MintPrice::<DebugApi> {
+                        token_id: managed_token_id!(THIRD_TIER_PRICE_TOKEN_IDS[i]),
+                        amount: managed_biguint!(THIRD_TIER_PRICE_AMOUNTS[i]),
+                    }),
+                    current_prices: ManagedVec::from_single_item(MintPrice::<DebugApi> {
+                        token_id: managed_token_id!(THIRD_TIER_PRICE_TOKEN_IDS[i]),
+                        amount: managed_biguint!(THIRD_TIER_PRICE_AMOUNTS[i]),
+                    }),
+                });
+            }
+            assert_eq!(
+                result.tier_info_entries.as_slice(),
+                expected_tier_info.as_slice()
+            );
+        })
+        .assert_ok();
 
     nm_setup
         .b_mock
-        .execute_tx(
-            &nm_setup.owner_address.clone(),
-            &nm_setup.nm_wrapper,
-            &rust_biguint!(0),
-            |sc| {
-                sc.set_mint_whitelist_expire_timestamp(
-                    managed_buffer!(FIRST_BRAND_ID),
-                    FIRST_MINT_START_TIMESTAMP + 1,
-                );
-            },
-        )
-        .assert_ok();
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
 
-    // try buy, not in whitelist
+    let first_user_addr = nm_setup.first_user_address.clone();
+    nm_setup.b_mock.set_esdt_balance(
+        &first_user_addr,
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(USER_STABLECOIN_BALANCE),
+    );
+
+    // try buy common tier with EGLD
     nm_setup
         .call_buy_random_nft(
             &first_user_addr,
-            FIRST_MINT_PRICE_TOKEN_ID,
-            FIRST_MINT_PRICE_AMOUNT,
-            FIRST_BRAND_ID,
-            first_tier,
+            EGLD_TOKEN_ID,
+            THIRD_TIER_PRICE_AMOUNTS[1],
+            THIRD_BRAND_ID,
+            THIRD_TIERS[1],
             1,
         )
-        .assert_user_error("Not in whitelist");
+        .assert_user_error("Invalid payment");
 
+    // buy common tier with stablecoin ok
     nm_setup
-        .b_mock
-        .execute_tx(
-            &nm_setup.owner_address.clone(),
-            &nm_setup.nm_wrapper,
-            &rust_biguint!(0),
-            |sc| {
-                let mut args = MultiValueEncoded::new();
-                args.push(managed_address!(&first_user_addr));
-                sc.add_to_whitelist(managed_buffer!(FIRST_BRAND_ID), args);
-            },
+        .call_buy_random_nft(
+            &first_user_addr,
+            STABLECOIN_TOKEN_ID,
+            THIRD_TIER_PRICE_AMOUNTS[1],
+            THIRD_BRAND_ID,
+            THIRD_TIERS[1],
+            1,
         )
         .assert_ok();
 
-    // buy ok
-
+    // buy legendary tier with EGLD ok
     nm_setup
         .call_buy_random_nft(
             &first_user_addr,
-            FIRST_MINT_PRICE_TOKEN_ID,
-            FIRST_MINT_PRICE_AMOUNT,
-            FIRST_BRAND_ID,
-            first_tier,
+            EGLD_TOKEN_ID,
+            THIRD_TIER_PRICE_AMOUNTS[0],
+            THIRD_BRAND_ID,
+            THIRD_TIERS[0],
             1,
         )
         .assert_ok();
+
+    nm_setup.b_mock.check_esdt_balance(
+        &first_user_addr,
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(USER_STABLECOIN_BALANCE - THIRD_TIER_PRICE_AMOUNTS[1]),
+    );
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let egld_payments = sc
+                .accumulated_mint_payments(&managed_buffer!(THIRD_BRAND_ID))
+                .get(&managed_token_id!(EGLD_TOKEN_ID))
+                .unwrap();
+            assert_eq!(egld_payments, managed_biguint!(THIRD_TIER_PRICE_AMOUNTS[0]));
+
+            let stablecoin_payments = sc
+                .accumulated_mint_payments(&managed_buffer!(THIRD_BRAND_ID))
+                .get(&managed_token_id!(STABLECOIN_TOKEN_ID))
+                .unwrap();
+            assert_eq!(
+                stablecoin_payments,
+                managed_biguint!(THIRD_TIER_PRICE_AMOUNTS[1])
+            );
+        })
+        .assert_ok();
 }
 
 #[test]
-fn giveaway_test() {
+fn claim_mint_payments_per_brand_test() {
     let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
-    nm_setup.create_default_brands();
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+    let owner_addr = nm_setup.owner_address.clone();
 
-    let first_tier = SECOND_TIERS[0];
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(SECOND_MINT_START_TIMESTAMP));
+    nm_setup
+        .call_set_mint_period(FIRST_BRAND_ID, FIRST_MINT_START_TIMESTAMP, u64::MAX)
+        .assert_ok();
 
-    // giveaway single nft
-    let first_user_addr = nm_setup.first_user_address.clone();
     nm_setup
-        .call_giveaway(
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            FIRST_TIERS[0],
+            1,
+        )
+        .assert_ok();
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            SECOND_MINT_PRICE_TOKEN_ID,
+            SECOND_MINT_PRICE_AMOUNT,
             SECOND_BRAND_ID,
-            first_tier,
-            [(first_user_addr.clone(), 1)].to_vec(),
+            SECOND_TIERS[0],
+            1,
         )
         .assert_ok();
 
-    // user received nonce 1 and ID 7
-    let mut attr = nm_setup.build_nft_attributes_second_token(7);
-    nm_setup.b_mock.check_nft_balance(
-        &first_user_addr,
-        SECOND_TOKEN_ID,
-        1,
-        &rust_biguint!(1),
-        Some(&attr),
-    );
-
     nm_setup
         .b_mock
         .execute_query(&nm_setup.nm_wrapper, |sc| {
-            let mapper = sc.available_ids(
-                &managed_buffer!(SECOND_BRAND_ID),
-                &managed_buffer!(first_tier),
-            );
-            assert_eq!(mapper.len(), 9);
-            assert_eq!(mapper.get(1), 1);
-            assert_eq!(mapper.get(2), 2);
-            assert_eq!(mapper.get(3), 3);
-            assert_eq!(mapper.get(4), 4);
-            assert_eq!(mapper.get(5), 5);
-            assert_eq!(mapper.get(6), 6);
-            assert_eq!(mapper.get(7), 10); // this changed
-            assert_eq!(mapper.get(8), 8);
-            assert_eq!(mapper.get(9), 9);
+            let pending_payments: Vec<_> = sc
+                .get_accumulated_mint_payments_per_brand()
+                .into_iter()
+                .collect();
+            assert_eq!(pending_payments.len(), 2);
+            for entry in pending_payments {
+                let (brand_id, payments) = entry.into_tuple();
+                let expected_amount = if brand_id == managed_buffer!(FIRST_BRAND_ID) {
+                    FIRST_MINT_PRICE_AMOUNT
+                } else {
+                    SECOND_MINT_PRICE_AMOUNT
+                };
+                assert_eq!(payments.len(), 1);
+                assert_eq!(payments.get(0).amount, managed_biguint!(expected_amount));
+            }
         })
         .assert_ok();
 
-    // giveaway two, single user
+    // second brand's creator claims their proceeds
     nm_setup
-        .call_giveaway(
-            SECOND_BRAND_ID,
-            first_tier,
-            [(first_user_addr.clone(), 2)].to_vec(),
-        )
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_mint_payments_claim_address_for_brand(
+                managed_buffer!(SECOND_BRAND_ID),
+                managed_address!(&second_user_addr),
+            );
+        })
         .assert_ok();
 
-    // received ID 4 and 5
-    attr = nm_setup.build_nft_attributes_second_token(4);
-    nm_setup.b_mock.check_nft_balance(
-        &first_user_addr,
-        SECOND_TOKEN_ID,
-        2,
-        &rust_biguint!(1),
-        Some(&attr),
-    );
-
-    attr = nm_setup.build_nft_attributes_second_token(5);
-    nm_setup.b_mock.check_nft_balance(
-        &first_user_addr,
-        SECOND_TOKEN_ID,
-        3,
-        &rust_biguint!(1),
-        Some(&attr),
-    );
-
     nm_setup
         .b_mock
-        .execute_query(&nm_setup.nm_wrapper, |sc| {
-            let mapper = sc.available_ids(
-                &managed_buffer!(SECOND_BRAND_ID),
-                &managed_buffer!(first_tier),
-            );
-            assert_eq!(mapper.len(), 7);
-            assert_eq!(mapper.get(1), 1);
-            assert_eq!(mapper.get(2), 2);
-            assert_eq!(mapper.get(3), 3);
-            assert_eq!(mapper.get(4), 9); // pos 4
-            assert_eq!(mapper.get(5), 8); // and 5 changed
-            assert_eq!(mapper.get(6), 6);
-            assert_eq!(mapper.get(7), 10);
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.claim_mint_payments(managed_buffer!(SECOND_BRAND_ID));
         })
-        .assert_ok();
+        .assert_user_error("Claim not allowed");
 
-    // giveaway, multiple users
-    let second_user_addr = nm_setup.second_user_address.clone();
     nm_setup
-        .call_giveaway(
-            SECOND_BRAND_ID,
-            first_tier,
-            [(first_user_addr.clone(), 2), (second_user_addr.clone(), 1)].to_vec(),
+        .b_mock
+        .execute_tx(
+            &second_user_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (egld_amt, _) = sc
+                    .claim_mint_payments(managed_buffer!(SECOND_BRAND_ID))
+                    .into_tuple();
+                assert_eq!(egld_amt, managed_biguint!(SECOND_MINT_PRICE_AMOUNT));
+            },
         )
         .assert_ok();
-
-    // user 1 received IDs 10 and 1
-    attr = nm_setup.build_nft_attributes_second_token(10);
-    nm_setup.b_mock.check_nft_balance(
-        &first_user_addr,
-        SECOND_TOKEN_ID,
-        4,
-        &rust_biguint!(1),
-        Some(&attr),
-    );
-
-    attr = nm_setup.build_nft_attributes_second_token(1);
-    nm_setup.b_mock.check_nft_balance(
-        &first_user_addr,
-        SECOND_TOKEN_ID,
-        5,
-        &rust_biguint!(1),
-        Some(&attr),
-    );
-
-    // user 2 received ID
-    attr = nm_setup.build_nft_attributes_second_token(6);
-    nm_setup.b_mock.check_nft_balance(
+    nm_setup.b_mock.check_egld_balance(
         &second_user_addr,
-        SECOND_TOKEN_ID,
-        6,
-        &rust_biguint!(1),
-        Some(&attr),
+        &rust_biguint!(USER_EGLD_BALANCE + SECOND_MINT_PRICE_AMOUNT),
     );
 
-    // mapper progress:
-    // 1 2 3 9 8 6 10
-    // 1 2 3 9 8 6
-    // 6 2 3 9 8
-    // 8 2 3 9
+    // first brand's proceeds are untouched
     nm_setup
         .b_mock
         .execute_query(&nm_setup.nm_wrapper, |sc| {
-            let mapper = sc.available_ids(
-                &managed_buffer!(SECOND_BRAND_ID),
-                &managed_buffer!(first_tier),
+            let pending_payments: Vec<_> = sc
+                .get_accumulated_mint_payments_per_brand()
+                .into_iter()
+                .collect();
+            assert_eq!(pending_payments.len(), 1);
+
+            let (brand_id, payments) = pending_payments[0].clone().into_tuple();
+            assert_eq!(brand_id, managed_buffer!(FIRST_BRAND_ID));
+            assert_eq!(
+                payments.get(0).amount,
+                managed_biguint!(FIRST_MINT_PRICE_AMOUNT)
             );
-            assert_eq!(mapper.len(), 4);
-            assert_eq!(mapper.get(1), 8);
-            assert_eq!(mapper.get(2), 2);
-            assert_eq!(mapper.get(3), 3);
-            assert_eq!(mapper.get(4), 9);
         })
         .assert_ok();
 }
 
 #[test]
-fn formatters_test() {
+fn claim_mint_payments_split_test() {
     let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
-    nm_setup.create_default_brands();
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let owner_addr = nm_setup.owner_address.clone();
+    let creator_addr = nm_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let platform_addr = nm_setup.b_mock.create_user_account(&rust_biguint!(0));
 
-    // test NFT attributes
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(SECOND_MINT_START_TIMESTAMP));
+
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let mut shares = MultiValueEncoded::new();
+            shares.push((managed_address!(&creator_addr), 8_500u32).into());
+            shares.push((managed_address!(&platform_addr), 1_000u32).into());
+            sc.set_mint_payments_split(managed_buffer!(SECOND_BRAND_ID), shares);
+        })
+        .assert_user_error("Shares must total 100%");
+
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let mut shares = MultiValueEncoded::new();
+            shares.push((managed_address!(&creator_addr), 8_500u32).into());
+            shares.push((managed_address!(&platform_addr), 1_500u32).into());
+            sc.set_mint_payments_split(managed_buffer!(SECOND_BRAND_ID), shares);
+        })
+        .assert_ok();
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            SECOND_MINT_PRICE_TOKEN_ID,
+            SECOND_MINT_PRICE_AMOUNT,
+            SECOND_BRAND_ID,
+            SECOND_TIERS[0],
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &first_user_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_mint_payments(managed_buffer!(SECOND_BRAND_ID));
+            },
+        )
+        .assert_user_error("Claim not allowed");
+
+    // one recipient triggers the payout for everyone
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &platform_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (egld_amt, _) = sc
+                    .claim_mint_payments(managed_buffer!(SECOND_BRAND_ID))
+                    .into_tuple();
+                assert_eq!(
+                    egld_amt,
+                    managed_biguint!(SECOND_MINT_PRICE_AMOUNT * 1_500 / 10_000)
+                );
+            },
+        )
+        .assert_ok();
+
+    nm_setup.b_mock.check_egld_balance(
+        &creator_addr,
+        &rust_biguint!(SECOND_MINT_PRICE_AMOUNT * 8_500 / 10_000),
+    );
+    nm_setup.b_mock.check_egld_balance(
+        &platform_addr,
+        &rust_biguint!(SECOND_MINT_PRICE_AMOUNT * 1_500 / 10_000),
+    );
+    nm_setup
+        .b_mock
+        .check_egld_balance(nm_setup.nm_wrapper.address_ref(), &rust_biguint!(0));
+
+    // split payments are kept for refunds once the brand is cancelled
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            SECOND_MINT_PRICE_TOKEN_ID,
+            SECOND_MINT_PRICE_AMOUNT,
+            SECOND_BRAND_ID,
+            SECOND_TIERS[0],
+            1,
+        )
+        .assert_ok();
+    nm_setup.call_cancel_brand(SECOND_BRAND_ID).assert_ok();
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &platform_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_mint_payments(managed_buffer!(SECOND_BRAND_ID));
+            },
+        )
+        .assert_user_error("Brand cancelled");
+}
+
+#[test]
+fn referral_rewards_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+    let owner_addr = nm_setup.owner_address.clone();
+    let referral_share = 1_000u32; // 10%
+    let referral_reward = SECOND_MINT_PRICE_AMOUNT * referral_share as u64 / 10_000;
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(SECOND_MINT_START_TIMESTAMP));
+
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_referral_share(managed_buffer!(SECOND_BRAND_ID), referral_share);
+        })
+        .assert_ok();
+
+    nm_setup
+        .call_buy_random_nft_with_referrer(
+            &first_user_addr,
+            SECOND_MINT_PRICE_AMOUNT,
+            SECOND_BRAND_ID,
+            SECOND_TIERS[0],
+            &first_user_addr,
+        )
+        .assert_user_error("May not refer yourself");
+
+    nm_setup
+        .call_buy_random_nft_with_referrer(
+            &first_user_addr,
+            SECOND_MINT_PRICE_AMOUNT,
+            SECOND_BRAND_ID,
+            SECOND_TIERS[0],
+            &second_user_addr,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let egld_id = EgldOrEsdtTokenIdentifier::egld();
+            assert_eq!(
+                sc.referral_rewards(&managed_address!(&second_user_addr))
+                    .get(&egld_id),
+                Some(managed_biguint!(referral_reward))
+            );
+            assert_eq!(
+                sc.accumulated_mint_payments(&managed_buffer!(SECOND_BRAND_ID))
+                    .get(&egld_id),
+                Some(managed_biguint!(SECOND_MINT_PRICE_AMOUNT - referral_reward))
+            );
+        })
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &second_user_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (egld_amt, _) = sc.claim_referral_rewards().into_tuple();
+                assert_eq!(egld_amt, managed_biguint!(referral_reward));
+            },
+        )
+        .assert_ok();
+    nm_setup.b_mock.check_egld_balance(
+        &second_user_addr,
+        &rust_biguint!(USER_EGLD_BALANCE + referral_reward),
+    );
+}
+
+#[test]
+fn platform_fee_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let owner_addr = nm_setup.owner_address.clone();
+    let platform_addr = nm_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let platform_fee = 500u32; // 5%
+    let platform_fee_amount = SECOND_MINT_PRICE_AMOUNT * platform_fee as u64 / 10_000;
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(SECOND_MINT_START_TIMESTAMP));
+
+    // normally set by the deployer on init or upgrade
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_platform_fee(managed_address!(&platform_addr), platform_fee);
+        })
+        .assert_ok();
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            SECOND_MINT_PRICE_TOKEN_ID,
+            SECOND_MINT_PRICE_AMOUNT,
+            SECOND_BRAND_ID,
+            SECOND_TIERS[0],
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let egld_id = EgldOrEsdtTokenIdentifier::egld();
+            assert_eq!(
+                sc.accumulated_platform_fees().get(&egld_id),
+                Some(managed_biguint!(platform_fee_amount))
+            );
+            assert_eq!(
+                sc.accumulated_mint_payments(&managed_buffer!(SECOND_BRAND_ID))
+                    .get(&egld_id),
+                Some(managed_biguint!(
+                    SECOND_MINT_PRICE_AMOUNT - platform_fee_amount
+                ))
+            );
+        })
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.claim_platform_fees();
+        })
+        .assert_user_error("Claim not allowed");
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &platform_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_platform_fees();
+            },
+        )
+        .assert_ok();
+    nm_setup
+        .b_mock
+        .check_egld_balance(&platform_addr, &rust_biguint!(platform_fee_amount));
+}
+
+#[test]
+fn edition_brand_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+
+    nm_setup.create_edition_brand();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    // the first buy creates the edition, the following ones add copies to the same nonce
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            EGLD_TOKEN_ID,
+            EDITION_PRICE_AMOUNT * 2,
+            EDITION_BRAND_ID,
+            EDITION_TIERS[0],
+            2,
+        )
+        .assert_ok();
+    nm_setup
+        .call_buy_random_nft(
+            &second_user_addr,
+            EGLD_TOKEN_ID,
+            EDITION_PRICE_AMOUNT,
+            EDITION_BRAND_ID,
+            EDITION_TIERS[0],
+            1,
+        )
+        .assert_ok();
+
+    nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+        &first_user_addr,
+        EDITION_TOKEN_ID,
+        1,
+        &rust_biguint!(2),
+        None,
+    );
+    nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+        &second_user_addr,
+        EDITION_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        None,
+    );
+
+    // the edition is sold out
+    nm_setup
+        .call_buy_random_nft(
+            &second_user_addr,
+            EGLD_TOKEN_ID,
+            EDITION_PRICE_AMOUNT,
+            EDITION_BRAND_ID,
+            EDITION_TIERS[0],
+            1,
+        )
+        .assert_user_error("Not enough NFTs available");
+
+    // each tier is its own edition
+    nm_setup
+        .call_buy_random_nft(
+            &second_user_addr,
+            EGLD_TOKEN_ID,
+            EDITION_PRICE_AMOUNT,
+            EDITION_BRAND_ID,
+            EDITION_TIERS[1],
+            1,
+        )
+        .assert_ok();
+    nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+        &second_user_addr,
+        EDITION_TOKEN_ID,
+        2,
+        &rust_biguint!(1),
+        None,
+    );
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_id = managed_buffer!(EDITION_BRAND_ID);
+            assert_eq!(
+                sc.edition_nonce(&brand_id, &managed_buffer!(EDITION_TIERS[0]))
+                    .get(),
+                1
+            );
+            assert_eq!(
+                sc.get_nfts_minted(&brand_id, &managed_buffer!(EDITION_TIERS[1])),
+                1
+            );
+        })
+        .assert_ok();
+
+    // copies are refunded to their buyers only
+    nm_setup
+        .call_giveaway(
+            EDITION_BRAND_ID,
+            EDITION_TIERS[1],
+            vec![(first_user_addr.clone(), 1)],
+        )
+        .assert_ok();
+    nm_setup.call_cancel_brand(EDITION_BRAND_ID).assert_ok();
+    nm_setup
+        .call_refund_nfts(&first_user_addr, EDITION_BRAND_ID, EDITION_TOKEN_ID, &[2])
+        .assert_user_error("NFT not refundable");
+
+    // the copies of one purchase may be refunded separately
+    for _ in 0..2 {
+        nm_setup
+            .call_refund_nfts(&first_user_addr, EDITION_BRAND_ID, EDITION_TOKEN_ID, &[1])
+            .assert_ok();
+    }
+    nm_setup
+        .call_refund_nfts(
+            &second_user_addr,
+            EDITION_BRAND_ID,
+            EDITION_TOKEN_ID,
+            &[1, 2],
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .check_egld_balance(&first_user_addr, &rust_biguint!(USER_EGLD_BALANCE));
+    nm_setup
+        .b_mock
+        .check_egld_balance(&second_user_addr, &rust_biguint!(USER_EGLD_BALANCE));
+}
+
+#[test]
+fn update_nft_attributes_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let original_uri: &[u8] = b"https://ipfs.io/ipfs/original.png";
+    let evolved_uri: &[u8] = b"https://ipfs.io/ipfs/evolved.png";
+    let evolved_thumbnail_uri: &[u8] = b"https://ipfs.io/ipfs/evolved_thumbnail.png";
+
+    nm_setup.create_default_brands();
+    nm_setup.create_dynamic_brand();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            DYNAMIC_BRAND_ID,
+            DYNAMIC_TIERS[0],
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .call_update_nft_attributes(FIRST_BRAND_ID, 1, DYNAMIC_EVOLVED_HASH, 1)
+        .assert_user_error("Not a dynamic brand");
+    nm_setup
+        .call_update_nft_attributes(DYNAMIC_BRAND_ID, 1, DYNAMIC_EVOLVED_HASH, 1)
+        .assert_user_error("Missing NFTRecreate role");
+    nm_setup
+        .call_update_nft_uris(DYNAMIC_BRAND_ID, 1, &[evolved_uri])
+        .assert_user_error("Missing NFTRecreate role");
+    nm_setup
+        .call_update_nft_uris(DYNAMIC_BRAND_ID, 1, &[])
+        .assert_user_error("No URIs provided");
+
+    nm_setup.b_mock.set_esdt_local_roles(
+        nm_setup.nm_wrapper.address_ref(),
+        DYNAMIC_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftRecreate][..],
+    );
+    nm_setup.set_minter_nft_copy(DYNAMIC_TOKEN_ID, 1, &[original_uri]);
+
+    let mut evolved_attributes = "metadata:".to_owned();
+    evolved_attributes += std::str::from_utf8(DYNAMIC_EVOLVED_HASH).unwrap();
+    evolved_attributes += "/1.json";
+    nm_setup
+        .call_update_nft_attributes(DYNAMIC_BRAND_ID, 1, DYNAMIC_EVOLVED_HASH, 1)
+        .assert_ok();
+    nm_setup.b_mock.check_nft_balance(
+        nm_setup.nm_wrapper.address_ref(),
+        DYNAMIC_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        Some(&evolved_attributes.as_bytes().to_vec()),
+    );
+
+    // the attributes are kept when only the URIs are updated
+    nm_setup
+        .call_update_nft_uris(DYNAMIC_BRAND_ID, 1, &[evolved_uri, evolved_thumbnail_uri])
+        .assert_ok();
+    nm_setup.check_minter_nft_metadata(
+        DYNAMIC_TOKEN_ID,
+        1,
+        evolved_attributes.as_bytes(),
+        &[evolved_uri, evolved_thumbnail_uri],
+    );
+
+    // the holder keeps the NFT
+    nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+        &first_user_addr,
+        DYNAMIC_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        None,
+    );
+}
+
+#[test]
+fn redemption_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_user_addr = nm_setup.first_user_address.clone();
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    // two gold NFTs (nonces 1 and 2) and a silver one (nonce 3)
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            FIRST_TIERS[0],
+            2,
+        )
+        .assert_ok();
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            FIRST_TIERS[1],
+            1,
+        )
+        .assert_ok();
+
+    // burn two gold NFTs for one NFT of the second brand, at most once
+    nm_setup
+        .call_add_redemption_program(
+            FIRST_BRAND_ID,
+            Some(FIRST_TIERS[0]),
+            2,
+            SECOND_BRAND_ID,
+            SECOND_TIERS[0],
+            FIRST_MINT_START_TIMESTAMP,
+            FIRST_MINT_END_TIMESTAMP,
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .call_redeem(&first_user_addr, 1, FIRST_TOKEN_ID, &[1, 3])
+        .assert_user_error("Invalid NFT tier");
+    nm_setup
+        .call_redeem(&first_user_addr, 1, FIRST_TOKEN_ID, &[1])
+        .assert_user_error("Invalid number of NFTs");
+
+    nm_setup
+        .call_redeem(&first_user_addr, 1, FIRST_TOKEN_ID, &[1, 2])
+        .assert_ok();
+    nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+        &first_user_addr,
+        FIRST_TOKEN_ID,
+        1,
+        &rust_biguint!(0),
+        None,
+    );
+    nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+        &first_user_addr,
+        SECOND_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        None,
+    );
+
+    nm_setup
+        .call_redeem(&first_user_addr, 1, FIRST_TOKEN_ID, &[3])
+        .assert_user_error("Max redemptions reached");
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_END_TIMESTAMP));
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_max_redemptions(1, 0);
+            },
+        )
+        .assert_ok();
+    nm_setup
+        .call_redeem(&first_user_addr, 1, FIRST_TOKEN_ID, &[3])
+        .assert_user_error("Redemption not active");
+}
+
+#[test]
+fn crafting_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let fee_amount = 5;
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup.b_mock.set_esdt_balance(
+        &first_user_addr,
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(USER_STABLECOIN_BALANCE),
+    );
+
+    // two silver NFTs (nonces 1 and 2) and a gold one (nonce 3)
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            FIRST_TIERS[1],
+            2,
+        )
+        .assert_ok();
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            FIRST_TIERS[0],
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .call_add_crafting_recipe(
+            SECOND_BRAND_ID,
+            SECOND_TIERS[0],
+            STABLECOIN_TOKEN_ID,
+            fee_amount,
+            &[
+                (FIRST_BRAND_ID, FIRST_TIERS[1], 2),
+                (FIRST_BRAND_ID, FIRST_TIERS[0], 1),
+            ],
+        )
+        .assert_ok();
+
+    nm_setup
+        .call_craft(&first_user_addr, 1, FIRST_TOKEN_ID, &[1, 2, 3], None)
+        .assert_user_error("Invalid fee");
+    nm_setup
+        .call_craft(
+            &first_user_addr,
+            1,
+            FIRST_TOKEN_ID,
+            &[1, 3],
+            Some((STABLECOIN_TOKEN_ID, fee_amount)),
+        )
+        .assert_user_error("Payment does not match recipe");
+
+    nm_setup
+        .call_craft(
+            &first_user_addr,
+            1,
+            FIRST_TOKEN_ID,
+            &[1, 2, 3],
+            Some((STABLECOIN_TOKEN_ID, fee_amount)),
+        )
+        .assert_ok();
+
+    nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+        &first_user_addr,
+        FIRST_TOKEN_ID,
+        3,
+        &rust_biguint!(0),
+        None,
+    );
+    nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+        &first_user_addr,
+        SECOND_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        None,
+    );
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            assert_eq!(
+                sc.accumulated_mint_payments(&managed_buffer!(SECOND_BRAND_ID))
+                    .get(&managed_token_id!(STABLECOIN_TOKEN_ID)),
+                Some(managed_biguint!(fee_amount))
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn reserved_supply_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+
+    nm_setup.create_reserved_supply_brand();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    // only the unreserved NFT can be bought
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            RESERVED_BRAND_ID,
+            RESERVED_TIER,
+            1,
+        )
+        .assert_ok();
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            RESERVED_BRAND_ID,
+            RESERVED_TIER,
+            1,
+        )
+        .assert_user_error("Not enough NFTs available");
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_info = sc.get_brand_info_view(managed_buffer!(RESERVED_BRAND_ID));
+            let tier_info = &brand_info.tier_info_entries[0];
+            assert_eq!(tier_info.available_nfts, 0);
+            assert_eq!(tier_info.reserved_nfts, RESERVED_TIER_RESERVED_NFTS);
+        })
+        .assert_ok();
+
+    // giveaways use up the reserve
+    nm_setup
+        .call_giveaway(
+            RESERVED_BRAND_ID,
+            RESERVED_TIER,
+            vec![(second_user_addr.clone(), RESERVED_TIER_RESERVED_NFTS)],
+        )
+        .assert_ok();
+    nm_setup
+        .call_giveaway(
+            RESERVED_BRAND_ID,
+            RESERVED_TIER,
+            vec![(second_user_addr, 1)],
+        )
+        .assert_user_error("Not enough NFTs available");
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_info = sc.get_brand_info_view(managed_buffer!(RESERVED_BRAND_ID));
+            let tier_info = &brand_info.tier_info_entries[0];
+            assert_eq!(tier_info.available_nfts, 0);
+            assert_eq!(tier_info.reserved_nfts, 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn claim_royalties_per_brand_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let second_user_addr = nm_setup.second_user_address.clone();
+    let owner_addr = nm_setup.owner_address.clone();
+    let royalties_amount = 500u64;
+
+    nm_setup.create_default_brands();
+
+    // simulate royalties claimed from the marketplace for each brand
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_royalties(
+                &managed_buffer!(FIRST_BRAND_ID),
+                managed_token_id!(STABLECOIN_TOKEN_ID),
+                managed_biguint!(royalties_amount),
+            );
+            sc.add_royalties(
+                &managed_buffer!(SECOND_BRAND_ID),
+                managed_token_id!(STABLECOIN_TOKEN_ID),
+                managed_biguint!(royalties_amount * 2),
+            );
+        })
+        .assert_ok();
+    nm_setup.b_mock.set_esdt_balance(
+        nm_setup.nm_wrapper.address_ref(),
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(royalties_amount * 3),
+    );
+
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_royalties_claim_address_for_brand(
+                managed_buffer!(SECOND_BRAND_ID),
+                managed_address!(&second_user_addr),
+            );
+        })
+        .assert_ok();
+
+    // the second brand's creator can only claim their share
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &second_user_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_royalties(managed_buffer!(FIRST_BRAND_ID));
+            },
+        )
+        .assert_user_error("Claim not allowed");
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &second_user_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_royalties(managed_buffer!(SECOND_BRAND_ID));
+            },
+        )
+        .assert_ok();
+    nm_setup.b_mock.check_esdt_balance(
+        &second_user_addr,
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(royalties_amount * 2),
+    );
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let pending_royalties: Vec<_> = sc
+                .get_accumulated_royalties_per_brand()
+                .into_iter()
+                .collect();
+            assert_eq!(pending_royalties.len(), 1);
+
+            let (brand_id, royalties) = pending_royalties[0].clone().into_tuple();
+            assert_eq!(brand_id, managed_buffer!(FIRST_BRAND_ID));
+            assert_eq!(royalties.get(0).amount, managed_biguint!(royalties_amount));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn claim_royalties_from_marketplace_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let owner_addr = nm_setup.owner_address.clone();
+    let minter_addr = nm_setup.nm_wrapper.address_ref().clone();
+    let egld_royalties = 1_000u64;
+    let stablecoin_royalties = 500u64;
+
+    nm_setup.create_default_brands();
+
+    let marketplace_wrapper = nm_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner_addr),
+        nft_marketplace_mock::contract_obj,
+        "marketplace mock path",
+    );
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &marketplace_wrapper,
+            &rust_biguint!(egld_royalties),
+            |sc| {
+                sc.deposit_royalties(managed_address!(&minter_addr));
+            },
+        )
+        .assert_ok();
+    nm_setup.b_mock.set_esdt_balance(
+        &owner_addr,
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(stablecoin_royalties),
+    );
+    nm_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &owner_addr,
+            &marketplace_wrapper,
+            STABLECOIN_TOKEN_ID,
+            0,
+            &rust_biguint!(stablecoin_royalties),
+            |sc| {
+                sc.deposit_royalties(managed_address!(&minter_addr));
+            },
+        )
+        .assert_ok();
+
+    let marketplace_addr = marketplace_wrapper.address_ref().clone();
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let mut tokens = MultiValueEncoded::new();
+            tokens.push(managed_token_id!(EGLD_TOKEN_ID));
+            sc.claim_royalties_from_marketplace(
+                managed_address!(&marketplace_addr),
+                managed_buffer!(b"UnknownBrand"),
+                tokens,
+            );
+        })
+        .assert_user_error("Invalid Brand ID");
+
+    // royalties are kept per payment token, not per NFT collection
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let mut tokens = MultiValueEncoded::new();
+            tokens.push(managed_token_id!(EGLD_TOKEN_ID));
+            tokens.push(managed_token_id!(STABLECOIN_TOKEN_ID));
+            sc.claim_royalties_from_marketplace(
+                managed_address!(&marketplace_addr),
+                managed_buffer!(FIRST_BRAND_ID),
+                tokens,
+            );
+        })
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .check_egld_balance(&minter_addr, &rust_biguint!(egld_royalties));
+    nm_setup.b_mock.check_esdt_balance(
+        &minter_addr,
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(stablecoin_royalties),
+    );
+    nm_setup
+        .b_mock
+        .check_egld_balance(&marketplace_addr, &rust_biguint!(0));
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let royalties_mapper = sc.accumulated_royalties(&managed_buffer!(FIRST_BRAND_ID));
+            assert_eq!(
+                royalties_mapper.get(&managed_token_id!(EGLD_TOKEN_ID)),
+                Some(managed_biguint!(egld_royalties))
+            );
+            assert_eq!(
+                royalties_mapper.get(&managed_token_id!(STABLECOIN_TOKEN_ID)),
+                Some(managed_biguint!(stablecoin_royalties))
+            );
+            assert!(sc
+                .accumulated_royalties(&managed_buffer!(SECOND_BRAND_ID))
+                .is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn multiple_prices_per_tier_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+
+    let first_tier = FIRST_TIERS[0];
+    let owner_addr = nm_setup.owner_address.clone();
+    let first_user_addr = nm_setup.first_user_address.clone();
+    nm_setup.b_mock.set_esdt_balance(
+        &first_user_addr,
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(USER_STABLECOIN_BALANCE),
+    );
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    // try buy with stablecoin, not accepted yet
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            STABLECOIN_TOKEN_ID,
+            STABLECOIN_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Invalid payment");
+
+    nm_setup
+        .call_add_price_for_tier(
+            FIRST_BRAND_ID,
+            first_tier,
+            STABLECOIN_TOKEN_ID,
+            STABLECOIN_PRICE_AMOUNT,
+        )
+        .assert_ok();
+
+    // try add same token again
+    nm_setup
+        .call_add_price_for_tier(
+            FIRST_BRAND_ID,
+            first_tier,
+            STABLECOIN_TOKEN_ID,
+            STABLECOIN_PRICE_AMOUNT,
+        )
+        .assert_user_error("Token already accepted for tier");
+
+    // buy with either token ok
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            STABLECOIN_TOKEN_ID,
+            STABLECOIN_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+        )
+        .assert_ok();
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let result = sc.get_brand_info_view(managed_buffer!(FIRST_BRAND_ID));
+            let tier_info = result.tier_info_entries.first().unwrap();
+            assert_eq!(tier_info.available_nfts, FIRST_NFT_AMOUNTS[0] - 3);
+            assert_eq!(tier_info.mint_prices.len(), 2);
+        })
+        .assert_ok();
+
+    // each token is accumulated separately
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let (egld_amt, other_payments) = sc
+                .claim_mint_payments(managed_buffer!(FIRST_BRAND_ID))
+                .into_tuple();
+            assert_eq!(egld_amt, managed_biguint!(FIRST_MINT_PRICE_AMOUNT));
+            assert_eq!(other_payments.len(), 1);
+
+            let stablecoin_payment = other_payments.get(0);
+            assert_eq!(
+                stablecoin_payment.token_identifier,
+                managed_token_id!(STABLECOIN_TOKEN_ID).unwrap_esdt()
+            );
+            assert_eq!(
+                stablecoin_payment.amount,
+                managed_biguint!(STABLECOIN_PRICE_AMOUNT * 2)
+            );
+        })
+        .assert_ok();
+
+    // remove EGLD price
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.remove_price_for_tier(
+                managed_buffer!(FIRST_BRAND_ID),
+                managed_buffer!(first_tier),
+                managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID),
+            );
+        })
+        .assert_ok();
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Invalid payment");
+
+    // try remove last price
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.remove_price_for_tier(
+                managed_buffer!(FIRST_BRAND_ID),
+                managed_buffer!(first_tier),
+                managed_token_id!(STABLECOIN_TOKEN_ID),
+            );
+        })
+        .assert_user_error("Tier must have at least one price");
+}
+
+#[test]
+fn set_price_for_tier_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+
+    let first_tier = FIRST_TIERS[0];
+    let new_price = FIRST_MINT_PRICE_AMOUNT * 2;
+    let first_user_addr = nm_setup.first_user_address.clone();
+
+    // try set price for token not accepted
+    nm_setup
+        .call_set_price_for_tier(FIRST_BRAND_ID, first_tier, STABLECOIN_TOKEN_ID, 1)
+        .assert_user_error("Token not accepted for tier");
+
+    nm_setup
+        .call_set_price_for_tier(
+            FIRST_BRAND_ID,
+            first_tier,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            new_price,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.lock_prices_after_mint_start(managed_buffer!(FIRST_BRAND_ID));
+            },
+        )
+        .assert_ok();
+
+    // price may still be changed before mint start
+    nm_setup
+        .call_set_price_for_tier(
+            FIRST_BRAND_ID,
+            first_tier,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            new_price,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    // old price no longer valid
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Invalid payment");
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            new_price,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    // try change price after mint start
+    nm_setup
+        .call_set_price_for_tier(
+            FIRST_BRAND_ID,
+            first_tier,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+        )
+        .assert_user_error("Prices may not be changed after mint start");
+    nm_setup
+        .call_add_price_for_tier(
+            FIRST_BRAND_ID,
+            first_tier,
+            STABLECOIN_TOKEN_ID,
+            STABLECOIN_PRICE_AMOUNT,
+        )
+        .assert_user_error("Prices may not be changed after mint start");
+
+    // other brands are not locked
+    nm_setup
+        .call_set_price_for_tier(
+            SECOND_BRAND_ID,
+            SECOND_TIERS[0],
+            SECOND_MINT_PRICE_TOKEN_ID,
+            new_price,
+        )
+        .assert_ok();
+}
+
+#[test]
+fn set_mint_period_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+
+    let first_tier = FIRST_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let postponed_start = FIRST_MINT_START_TIMESTAMP + 1_000;
+    let extended_end = FIRST_MINT_END_TIMESTAMP + 1_000;
+
+    // try set invalid period
+    nm_setup
+        .call_set_mint_period(FIRST_BRAND_ID, extended_end, postponed_start)
+        .assert_user_error("Invalid timestamps");
+
+    // postpone launch
+    nm_setup
+        .call_set_mint_period(FIRST_BRAND_ID, postponed_start, FIRST_MINT_END_TIMESTAMP)
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("May not mint yet");
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(postponed_start));
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    // try move start after mint started
+    nm_setup
+        .call_set_mint_period(FIRST_BRAND_ID, postponed_start + 1, extended_end)
+        .assert_user_error("May not change start after mint started");
+
+    // extend mint
+    nm_setup
+        .call_set_mint_period(FIRST_BRAND_ID, postponed_start, extended_end)
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_END_TIMESTAMP));
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let result = sc.get_brand_info_view(managed_buffer!(FIRST_BRAND_ID));
+            assert_eq!(
+                result.brand_info.mint_period,
+                TimePeriod {
+                    start: postponed_start,
+                    end: extended_end,
+                }
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn dutch_auction_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+
+    let first_tier = FIRST_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let owner_addr = nm_setup.owner_address.clone();
+
+    // start 2_000, floor 500, decreases by 100 every 10 seconds
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_dutch_auction_for_tier(
+                managed_buffer!(FIRST_BRAND_ID),
+                managed_buffer!(first_tier),
+                managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID),
+                managed_biguint!(2_000),
+                managed_biguint!(500),
+                managed_biguint!(100),
+                10,
+            );
+        })
+        .assert_ok();
+
+    // price stays at start price before mint start
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(1));
+    nm_setup.check_current_price(FIRST_BRAND_ID, first_tier, 2_000);
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP + 25));
+    nm_setup.check_current_price(FIRST_BRAND_ID, first_tier, 1_800);
+
+    // try buy at static price
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Invalid payment");
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            1_800 * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+        )
+        .assert_ok();
+
+    // price does not go below floor
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP + 1_000));
+    nm_setup.check_current_price(FIRST_BRAND_ID, first_tier, 500);
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            500,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let auction = sc
+                .dutch_auction_for_tier(
+                    &managed_buffer!(FIRST_BRAND_ID),
+                    &managed_buffer!(first_tier),
+                    &managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID),
+                )
+                .get();
+            assert_eq!(
+                auction,
+                DutchAuction {
+                    start_price: managed_biguint!(2_000),
+                    floor_price: managed_biguint!(500),
+                    price_decay_step: managed_biguint!(100),
+                    decay_interval: 10,
+                }
+            );
+        })
+        .assert_ok();
+
+    // static price is used again after removing the auction
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.remove_dutch_auction_for_tier(
+                managed_buffer!(FIRST_BRAND_ID),
+                managed_buffer!(first_tier),
+                managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID),
+            );
+        })
+        .assert_ok();
+    nm_setup.check_current_price(FIRST_BRAND_ID, first_tier, FIRST_MINT_PRICE_AMOUNT);
+}
+
+#[test]
+fn bonding_curve_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+
+    // legendary: +1_000 every 2 NFTs, common: +5 for each NFT
+    nm_setup.create_mixed_price_brand_with_curves(&[
+        Some(|| PriceCurve::Stepwise {
+            step_size: 2,
+            price_increment: managed_biguint!(1_000),
+        }),
+        Some(|| PriceCurve::Linear {
+            price_increment: managed_biguint!(5),
+        }),
+    ]);
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    let legendary_tier = THIRD_TIERS[0];
+    let common_tier = THIRD_TIERS[1];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    nm_setup.b_mock.set_esdt_balance(
+        &first_user_addr,
+        STABLECOIN_TOKEN_ID,
+        &rust_biguint!(USER_STABLECOIN_BALANCE),
+    );
+
+    // first NFTs at base price
+    nm_setup.check_current_price(THIRD_BRAND_ID, common_tier, 20);
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            STABLECOIN_TOKEN_ID,
+            20,
+            THIRD_BRAND_ID,
+            common_tier,
+            1,
+        )
+        .assert_ok();
+    nm_setup.check_current_price(THIRD_BRAND_ID, common_tier, 25);
+
+    // multiple NFTs pay the sum of each step
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            STABLECOIN_TOKEN_ID,
+            25 * 2,
+            THIRD_BRAND_ID,
+            common_tier,
+            2,
+        )
+        .assert_user_error("Invalid payment");
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            STABLECOIN_TOKEN_ID,
+            25 + 30,
+            THIRD_BRAND_ID,
+            common_tier,
+            2,
+        )
+        .assert_ok();
+    nm_setup.check_current_price(THIRD_BRAND_ID, common_tier, 35);
+
+    // giveaways consume supply as well
+    nm_setup
+        .call_giveaway(
+            THIRD_BRAND_ID,
+            common_tier,
+            [(first_user_addr.clone(), 1)].to_vec(),
+        )
+        .assert_ok();
+    nm_setup.check_current_price(THIRD_BRAND_ID, common_tier, 40);
+
+    // stepwise curve
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            EGLD_TOKEN_ID,
+            5_000,
+            THIRD_BRAND_ID,
+            legendary_tier,
+            1,
+        )
+        .assert_ok();
+    nm_setup.check_current_price(THIRD_BRAND_ID, legendary_tier, 5_000);
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            EGLD_TOKEN_ID,
+            5_000,
+            THIRD_BRAND_ID,
+            legendary_tier,
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let result = sc.get_brand_info_view(managed_buffer!(THIRD_BRAND_ID));
+            let legendary_tier_info = result.tier_info_entries.first().unwrap();
+            assert_eq!(legendary_tier_info.available_nfts, 0);
+            assert_eq!(
+                legendary_tier_info.current_prices.get(0).amount,
+                managed_biguint!(6_000)
+            );
+            assert_eq!(
+                legendary_tier_info.mint_prices.get(0).amount,
+                managed_biguint!(5_000)
+            );
+        })
+        .assert_ok();
+
+    // bonding curve may not be combined with a dutch auction
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_dutch_auction_for_tier(
+                    managed_buffer!(THIRD_BRAND_ID),
+                    managed_buffer!(common_tier),
+                    managed_token_id!(STABLECOIN_TOKEN_ID),
+                    managed_biguint!(100),
+                    managed_biguint!(10),
+                    managed_biguint!(1),
+                    10,
+                );
+            },
+        )
+        .assert_user_error("Tier price follows a bonding curve");
+}
+
+#[test]
+fn mint_limits_per_address_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    let first_tier = FIRST_TIERS[0];
+    let second_tier = FIRST_TIERS[1];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_max_nfts_per_address(managed_buffer!(FIRST_BRAND_ID), 3);
+                sc.set_max_nfts_per_address_for_tier(
+                    managed_buffer!(FIRST_BRAND_ID),
+                    managed_buffer!(first_tier),
+                    2,
+                );
+            },
+        )
+        .assert_ok();
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+        )
+        .assert_ok();
+
+    // try buy over tier limit
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Max NFTs per address for tier limit exceeded");
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            second_tier,
+            1,
+        )
+        .assert_ok();
+
+    // try buy over brand limit
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            second_tier,
+            1,
+        )
+        .assert_user_error("Max NFTs per address limit exceeded");
+
+    // giveaways are not limited
+    nm_setup
+        .call_giveaway(
+            FIRST_BRAND_ID,
+            first_tier,
+            [(first_user_addr.clone(), 1)].to_vec(),
+        )
+        .assert_ok();
+
+    // other users have their own limit
+    nm_setup
+        .call_buy_random_nft(
+            &second_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_id = managed_buffer!(FIRST_BRAND_ID);
+            assert_eq!(
+                sc.minted_count_for_address(&brand_id, &managed_address!(&first_user_addr))
+                    .get(),
+                3
+            );
+            assert_eq!(
+                sc.minted_count_for_address(&brand_id, &managed_address!(&second_user_addr))
+                    .get(),
+                1
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_whitelist_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_tier = FIRST_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let presale_price = FIRST_MINT_PRICE_AMOUNT / 2;
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_mint_whitelist_expire_timestamp(
+                    managed_buffer!(FIRST_BRAND_ID),
+                    FIRST_MINT_START_TIMESTAMP + 1,
+                );
+            },
+        )
+        .assert_ok();
+
+    // try buy, not in whitelist
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Not in whitelist");
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push((managed_address!(&first_user_addr), 0).into());
+                sc.add_to_whitelist(managed_buffer!(FIRST_BRAND_ID), args);
+            },
+        )
+        .assert_user_error("Invalid whitelist quota");
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push((managed_address!(&first_user_addr), 2).into());
+                sc.add_to_whitelist(managed_buffer!(FIRST_BRAND_ID), args);
+
+                sc.set_presale_price_for_tier(
+                    managed_buffer!(FIRST_BRAND_ID),
+                    managed_buffer!(first_tier),
+                    managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID),
+                    managed_biguint!(presale_price),
+                );
+            },
+        )
+        .assert_ok();
+
+    // try buy at full price during presale
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Invalid payment");
+
+    // buy ok
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            presale_price,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    // try buy over quota
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            presale_price * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+        )
+        .assert_user_error("Whitelist quota exceeded");
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            presale_price,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            presale_price,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Whitelist quota exceeded");
+
+    // public sale, quota and presale price no longer apply
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP + 1));
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            presale_price,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Invalid payment");
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+}
+
+#[test]
+fn buy_merkle_whitelist_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_tier = FIRST_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_mint_whitelist_expire_timestamp(
+                    managed_buffer!(FIRST_BRAND_ID),
+                    FIRST_MINT_START_TIMESTAMP + 1,
+                );
+            },
+        )
+        .assert_ok();
+
+    let first_leaf = nm_setup.compute_merkle_leaf(&first_user_addr, 2);
+    let second_leaf = nm_setup.compute_merkle_leaf(&second_user_addr, 1);
+    let merkle_root = nm_setup.hash_merkle_pair(&first_leaf, &second_leaf);
+
+    // try buy before the root is set
+    nm_setup
+        .call_buy_random_nft_with_merkle_proof(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+            2,
+            &[second_leaf],
+        )
+        .assert_user_error("No Merkle root set for brand");
+
+    nm_setup
+        .call_set_whitelist_merkle_root(FIRST_BRAND_ID, &merkle_root)
+        .assert_ok();
+
+    // try buy with a forged quota
+    nm_setup
+        .call_buy_random_nft_with_merkle_proof(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+            3,
+            &[second_leaf],
+        )
+        .assert_user_error("Invalid Merkle proof");
+
+    nm_setup
+        .call_buy_random_nft_with_merkle_proof(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+            2,
+            &[second_leaf],
+        )
+        .assert_ok();
+
+    // quota already used
+    nm_setup
+        .call_buy_random_nft_with_merkle_proof(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+            2,
+            &[second_leaf],
+        )
+        .assert_user_error("Whitelist quota exceeded");
+
+    // proofs are not accepted for a different address
+    nm_setup
+        .call_buy_random_nft_with_merkle_proof(
+            &second_user_addr,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+            2,
+            &[second_leaf],
+        )
+        .assert_user_error("Invalid Merkle proof");
+
+    // without a proof, the regular whitelist is used
+    nm_setup
+        .call_buy_random_nft(
+            &second_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Not in whitelist");
+
+    nm_setup
+        .call_buy_random_nft_with_merkle_proof(
+            &second_user_addr,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+            1,
+            &[first_leaf],
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_id = managed_buffer!(FIRST_BRAND_ID);
+            assert_eq!(
+                sc.merkle_whitelist_minted_count(&brand_id, &managed_address!(&first_user_addr))
+                    .get(),
+                2
+            );
+            assert_eq!(
+                sc.merkle_whitelist_minted_count(&brand_id, &managed_address!(&second_user_addr))
+                    .get(),
+                1
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn mint_phases_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_tier = FIRST_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+    let og_phase_end = FIRST_MINT_START_TIMESTAMP + 10;
+    let public_phase_end = FIRST_MINT_START_TIMESTAMP + 20;
+    let og_price_amount = FIRST_MINT_PRICE_AMOUNT / 2;
+
+    let og_price_overrides: &[(&[u8], &[u8], u64)] =
+        &[(first_tier, FIRST_MINT_PRICE_TOKEN_ID, og_price_amount)];
+
+    nm_setup.create_default_brands();
+
+    // try override the price of an unknown tier
+    nm_setup
+        .call_add_mint_phase(
+            FIRST_BRAND_ID,
+            b"og",
+            FIRST_MINT_START_TIMESTAMP,
+            og_phase_end,
+            2,
+            false,
+            &[(b"diamond", FIRST_MINT_PRICE_TOKEN_ID, og_price_amount)],
+        )
+        .assert_user_error("Invalid tier");
+
+    nm_setup
+        .call_add_mint_phase(
+            FIRST_BRAND_ID,
+            b"og",
+            FIRST_MINT_START_TIMESTAMP,
+            og_phase_end,
+            2,
+            false,
+            og_price_overrides,
+        )
+        .assert_ok();
+
+    // phases added after clearing do not inherit the old eligibility lists
+    let owner_addr = nm_setup.owner_address.clone();
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let mut users = MultiValueEncoded::new();
+            users.push(managed_address!(&second_user_addr));
+            sc.add_to_phase_eligibility_list(managed_buffer!(FIRST_BRAND_ID), 1, users);
+
+            sc.clear_mint_phases(managed_buffer!(FIRST_BRAND_ID));
+        })
+        .assert_ok();
+    nm_setup
+        .call_add_mint_phase(
+            FIRST_BRAND_ID,
+            b"og",
+            FIRST_MINT_START_TIMESTAMP,
+            og_phase_end,
+            2,
+            false,
+            og_price_overrides,
+        )
+        .assert_ok();
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            assert!(sc
+                .phase_eligibility_list(&managed_buffer!(FIRST_BRAND_ID), 1)
+                .is_empty());
+        })
+        .assert_ok();
+
+    // try add overlapping phase
+    nm_setup
+        .call_add_mint_phase(
+            FIRST_BRAND_ID,
+            b"public",
+            og_phase_end - 1,
+            public_phase_end,
+            0,
+            true,
+            &[],
+        )
+        .assert_user_error("Mint phases may not overlap");
+
+    nm_setup
+        .call_add_mint_phase(
+            FIRST_BRAND_ID,
+            b"public",
+            og_phase_end,
+            public_phase_end,
+            0,
+            true,
+            &[],
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let (phase_id, phase) = sc
+                .get_active_phase_view(managed_buffer!(FIRST_BRAND_ID))
+                .into_option()
+                .unwrap()
+                .into_tuple();
+            assert_eq!(phase_id, 1);
+            assert_eq!(phase.name, managed_buffer!(b"og"));
+        })
+        .assert_ok();
+
+    // try buy, not eligible
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            og_price_amount,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Not eligible for mint phase");
+
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut users = MultiValueEncoded::new();
+                users.push(managed_address!(&first_user_addr));
+                sc.add_to_phase_eligibility_list(managed_buffer!(FIRST_BRAND_ID), 1, users);
+            },
+        )
+        .assert_ok();
+
+    // try buy at tier price
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Invalid payment");
+
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            og_price_amount,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+
+    // tiers without a price override keep their price
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            FIRST_TIERS[1],
+            1,
+        )
+        .assert_ok();
+
+    // try buy over the phase wallet cap
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            og_price_amount,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Max NFTs per address for phase limit exceeded");
+
+    // public phase, tier prices apply again
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(og_phase_end));
+    nm_setup
+        .call_buy_random_nft(
+            &second_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+        )
+        .assert_ok();
+
+    // after the last phase, even though the brand mint period did not end
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(public_phase_end));
+    nm_setup
+        .call_buy_random_nft(
+            &second_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("No active mint phase");
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            assert!(sc
+                .get_active_phase_view(managed_buffer!(FIRST_BRAND_ID))
+                .into_option()
+                .is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn delayed_reveal_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_tier = DYNAMIC_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let placeholder_uri: &[u8] = b"ipfs://placeholder.json";
+
+    nm_setup.create_default_brands();
+    nm_setup.create_dynamic_brand();
+    nm_setup
+        .call_enable_delayed_reveal(FIRST_BRAND_ID, placeholder_uri)
+        .assert_user_error("Delayed reveal requires a dynamic brand");
+    nm_setup
+        .call_enable_delayed_reveal(DYNAMIC_BRAND_ID, placeholder_uri)
+        .assert_ok();
+    nm_setup
+        .call_schedule_reveal(SECOND_BRAND_ID)
+        .assert_user_error("Brand does not use delayed reveal");
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            DYNAMIC_BRAND_ID,
+            first_tier,
+            2,
+        )
+        .assert_ok();
+
+    // user receives placeholders, numbered in purchase order
+    for nonce in 1..=2u64 {
+        nm_setup.b_mock.check_nft_balance(
+            &first_user_addr,
+            DYNAMIC_TOKEN_ID,
+            nonce,
+            &rust_biguint!(1),
+            Some(&Vec::<u8>::new()),
+        );
+    }
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_id = managed_buffer!(DYNAMIC_BRAND_ID);
+            for nonce in 1..=2u64 {
+                let placeholder_info = sc.placeholder_info(&brand_id, nonce).get();
+                assert_eq!(placeholder_info.tier, managed_buffer!(first_tier));
+                assert_eq!(placeholder_info.placeholder_id, nonce as usize);
+            }
+            assert_eq!(
+                sc.get_nfts_minted(&brand_id, &managed_buffer!(first_tier)),
+                2
+            );
+        })
+        .assert_ok();
+
+    nm_setup
+        .call_reveal_nfts(DYNAMIC_BRAND_ID, &[1])
+        .assert_user_error("Not revealed yet");
+
+    nm_setup.call_schedule_reveal(DYNAMIC_BRAND_ID).assert_ok();
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            DYNAMIC_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Minting closed, reveal is scheduled");
+    nm_setup
+        .call_reveal(DYNAMIC_BRAND_ID)
+        .assert_user_error("Reveal block not reached yet");
+
+    nm_setup.b_mock.set_block_nonce(REVEAL_BLOCK_DELAY);
+    nm_setup.call_reveal(DYNAMIC_BRAND_ID).assert_ok();
+    nm_setup
+        .call_reveal(DYNAMIC_BRAND_ID)
+        .assert_user_error("Already revealed");
+
+    let mut expected_ids = Vec::new();
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_id = managed_buffer!(DYNAMIC_BRAND_ID);
+            let tier = managed_buffer!(first_tier);
+            let offset = sc.reveal_offset(&brand_id, &tier).get();
+            let id_offset = sc.nft_id_offset_for_tier(&brand_id, &tier).get();
+            let total_nfts = DYNAMIC_NFT_AMOUNTS[0];
+            for placeholder_id in 1..=2 {
+                expected_ids.push((placeholder_id - 1 + offset) % total_nfts + 1 + id_offset);
+            }
+        })
+        .assert_ok();
+
+    nm_setup.b_mock.set_esdt_local_roles(
+        nm_setup.nm_wrapper.address_ref(),
+        DYNAMIC_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftRecreate][..],
+    );
+    for nonce in 1..=2u64 {
+        nm_setup.set_minter_nft_copy(DYNAMIC_TOKEN_ID, nonce, &[placeholder_uri]);
+    }
+    nm_setup
+        .call_reveal_nfts(DYNAMIC_BRAND_ID, &[1, 2])
+        .assert_ok();
+
+    // placeholders are updated in place, the real URIs replacing the placeholder URI
+    for (i, expected_id) in expected_ids.iter().enumerate() {
+        let placeholder_nonce = i as u64 + 1;
+        let expected_attributes = nm_setup.build_nft_attributes_dynamic_token(*expected_id);
+        let expected_uris = nm_setup.build_nft_uris_dynamic_token(*expected_id);
+        let expected_uris: Vec<&[u8]> = expected_uris.iter().map(|uri| uri.as_bytes()).collect();
+        nm_setup.check_minter_nft_metadata(
+            DYNAMIC_TOKEN_ID,
+            placeholder_nonce,
+            expected_attributes.as_bytes(),
+            &expected_uris,
+        );
+    }
+
+    // already revealed NFTs are not placeholders anymore
+    nm_setup
+        .call_reveal_nfts(DYNAMIC_BRAND_ID, &[1])
+        .assert_user_error("Not a placeholder NFT");
+}
+
+#[test]
+fn commit_reveal_bonding_curve_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_mixed_price_brand_with_curves(&[
+        Some(|| PriceCurve::Linear {
+            price_increment: managed_biguint!(100),
+        }),
+        None,
+    ]);
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup.b_mock.set_block_nonce(1);
+
+    let legendary_tier = THIRD_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+
+    nm_setup
+        .call_commit_buy(&first_user_addr, 5_000, THIRD_BRAND_ID, legendary_tier, 1)
+        .assert_ok();
+    nm_setup.check_current_price(THIRD_BRAND_ID, legendary_tier, 5_100);
+
+    // the pending buy already moved the curve
+    nm_setup
+        .call_commit_buy(&second_user_addr, 5_000, THIRD_BRAND_ID, legendary_tier, 1)
+        .assert_user_error("Invalid payment");
+    nm_setup
+        .call_commit_buy(&second_user_addr, 5_100, THIRD_BRAND_ID, legendary_tier, 1)
+        .assert_ok();
+    nm_setup.check_current_price(THIRD_BRAND_ID, legendary_tier, 5_200);
+
+    nm_setup.b_mock.set_block_nonce(2);
+    nm_setup.call_reveal_buy(&first_user_addr).assert_ok();
+    nm_setup.call_reveal_buy(&second_user_addr).assert_ok();
+    nm_setup.check_current_price(THIRD_BRAND_ID, legendary_tier, 5_200);
+
+    nm_setup
+        .b_mock
+        .check_egld_balance(&first_user_addr, &rust_biguint!(USER_EGLD_BALANCE - 5_000));
+    nm_setup
+        .b_mock
+        .check_egld_balance(&second_user_addr, &rust_biguint!(USER_EGLD_BALANCE - 5_100));
+}
+
+#[test]
+fn commit_reveal_buy_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_tier = FIRST_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup.b_mock.set_block_nonce(1);
+
+    nm_setup
+        .call_commit_buy(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+        )
+        .assert_ok();
+    nm_setup
+        .call_commit_buy(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Pending buy already exists");
+
+    // payment is locked and NFTs are reserved, but none are minted yet
+    nm_setup.b_mock.check_egld_balance(
+        &first_user_addr,
+        &rust_biguint!(USER_EGLD_BALANCE - FIRST_MINT_PRICE_AMOUNT * 2),
+    );
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_id = managed_buffer!(FIRST_BRAND_ID);
+            let tier = managed_buffer!(first_tier);
+            assert_eq!(sc.committed_nfts(&brand_id, &tier).get(), 2);
+            assert_eq!(
+                sc.get_available_nfts(&brand_id, &tier),
+                FIRST_NFT_AMOUNTS[0] - 2
+            );
+            assert_eq!(sc.get_nfts_minted(&brand_id, &tier), 0);
+        })
+        .assert_ok();
+
+    nm_setup
+        .call_reveal_buy(&first_user_addr)
+        .assert_user_error("May not reveal in the commit block");
+
+    nm_setup.b_mock.set_block_nonce(2);
+    nm_setup.call_reveal_buy(&first_user_addr).assert_ok();
+    for nonce in 1..=2u64 {
+        nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+            &first_user_addr,
+            FIRST_TOKEN_ID,
+            nonce,
+            &rust_biguint!(1),
+            None,
+        );
+    }
+
+    // not revealed in time
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.owner_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_max_nfts_per_address(managed_buffer!(FIRST_BRAND_ID), 1);
+            },
+        )
+        .assert_ok();
+    nm_setup
+        .call_commit_buy(
+            &second_user_addr,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+    nm_setup
+        .call_refund_expired_buy(&second_user_addr)
+        .assert_user_error("Pending buy not expired yet");
+
+    nm_setup
+        .b_mock
+        .set_block_nonce(2 + COMMIT_EXPIRY_BLOCKS + 1);
+    nm_setup
+        .call_reveal_buy(&second_user_addr)
+        .assert_user_error("Pending buy expired");
+    nm_setup
+        .call_refund_expired_buy(&second_user_addr)
+        .assert_ok();
+    nm_setup
+        .call_refund_expired_buy(&second_user_addr)
+        .assert_user_error("No pending buy");
+
+    nm_setup
+        .b_mock
+        .check_egld_balance(&second_user_addr, &rust_biguint!(USER_EGLD_BALANCE));
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let brand_id = managed_buffer!(FIRST_BRAND_ID);
+            let tier = managed_buffer!(first_tier);
+            assert_eq!(sc.committed_nfts(&brand_id, &tier).get(), 0);
+            assert_eq!(
+                sc.get_available_nfts(&brand_id, &tier),
+                FIRST_NFT_AMOUNTS[0] - 2
+            );
+            assert_eq!(
+                sc.minted_count_for_address(&brand_id, &managed_address!(&second_user_addr))
+                    .get(),
+                0
+            );
+        })
+        .assert_ok();
+
+    // the refund gave back the per-address limit
+    nm_setup
+        .call_buy_random_nft(
+            &second_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_ok();
+}
+
+#[test]
+fn refund_cancelled_brand_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_tier = FIRST_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+    let owner_addr = nm_setup.owner_address.clone();
+    let platform_addr = nm_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+        )
+        .assert_ok();
+    nm_setup
+        .call_giveaway(
+            FIRST_BRAND_ID,
+            first_tier,
+            vec![(second_user_addr.clone(), 1)],
+        )
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_platform_fee(managed_address!(&platform_addr), 500);
+            sc.set_referral_share(managed_buffer!(FIRST_BRAND_ID), 1_000);
+        })
+        .assert_ok();
+    nm_setup
+        .call_buy_random_nft_with_referrer(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            &second_user_addr,
+        )
+        .assert_ok();
+
+    nm_setup
+        .call_refund_nfts(&first_user_addr, FIRST_BRAND_ID, FIRST_TOKEN_ID, &[1])
+        .assert_user_error("Brand not cancelled");
+
+    nm_setup.call_cancel_brand(FIRST_BRAND_ID).assert_ok();
+    nm_setup
+        .call_cancel_brand(FIRST_BRAND_ID)
+        .assert_user_error("Brand already cancelled");
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Brand cancelled");
+
+    // mint payments stay in the contract for refunds
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.claim_mint_payments(managed_buffer!(FIRST_BRAND_ID));
+        })
+        .assert_user_error("Brand cancelled");
+
+    // giveaway NFTs were not paid for
+    nm_setup
+        .call_refund_nfts(&second_user_addr, FIRST_BRAND_ID, FIRST_TOKEN_ID, &[3])
+        .assert_user_error("NFT not refundable");
+
+    nm_setup
+        .call_refund_nfts(&first_user_addr, FIRST_BRAND_ID, FIRST_TOKEN_ID, &[1, 2, 4])
+        .assert_ok();
+
+    // the platform fee and the referral reward are not refunded
+    let platform_fee = FIRST_MINT_PRICE_AMOUNT * 500 / 10_000;
+    let referral_reward = (FIRST_MINT_PRICE_AMOUNT - platform_fee) * 1_000 / 10_000;
+    nm_setup.b_mock.check_egld_balance(
+        &first_user_addr,
+        &rust_biguint!(USER_EGLD_BALANCE - platform_fee - referral_reward),
+    );
+    for nonce in [1u64, 2, 4] {
+        nm_setup.b_mock.check_nft_balance::<Vec<u8>>(
+            &first_user_addr,
+            FIRST_TOKEN_ID,
+            nonce,
+            &rust_biguint!(0),
+            None,
+        );
+    }
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            assert!(sc
+                .accumulated_mint_payments(&managed_buffer!(FIRST_BRAND_ID))
+                .is_empty());
+        })
+        .assert_ok();
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &platform_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (egld_amt, _) = sc.claim_platform_fees().into_tuple();
+                assert_eq!(egld_amt, managed_biguint!(platform_fee));
+            },
+        )
+        .assert_ok();
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &second_user_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (egld_amt, _) = sc.claim_referral_rewards().into_tuple();
+                assert_eq!(egld_amt, managed_biguint!(referral_reward));
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn buy_with_mint_signature_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    let first_tier = FIRST_TIERS[0];
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let second_user_addr = nm_setup.second_user_address.clone();
+    let expiry = FIRST_MINT_START_TIMESTAMP + 10;
+
+    let signer_secret = SecretKey::from_bytes(&[7u8; 32]).unwrap();
+    let signer_public = PublicKey::from(&signer_secret);
+    let signer = Keypair {
+        secret: signer_secret,
+        public: signer_public,
+    };
+    let signer_address = Address::from(signer_public.to_bytes());
+    let minter_addr = nm_setup.nm_wrapper.address_ref().clone();
+
+    nm_setup.create_default_brands();
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup
+        .call_set_mint_signer(FIRST_BRAND_ID, &signer_address)
+        .assert_ok();
+
+    // try buy without signature
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+        )
+        .assert_user_error("Mint signature required");
+
+    let message = nm_setup.build_mint_signature_message(
+        &minter_addr,
+        &first_user_addr,
+        FIRST_BRAND_ID,
+        first_tier,
+        2,
+        expiry,
+        1,
+    );
+    let signature = signer.sign(&message).to_bytes();
+
+    // try use the signature for a different quantity
+    nm_setup
+        .call_buy_random_nft_with_signature(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+            expiry,
+            1,
+            &signature,
+        )
+        .assert_error(10, "ed25519 verify error");
+
+    // try use the signature from a different address
+    nm_setup
+        .call_buy_random_nft_with_signature(
+            &second_user_addr,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+            expiry,
+            1,
+            &signature,
+        )
+        .assert_error(10, "ed25519 verify error");
+
+    // try use a signature meant for a different minter
+    let other_minter_message = nm_setup.build_mint_signature_message(
+        &nm_setup.owner_address,
+        &first_user_addr,
+        FIRST_BRAND_ID,
+        first_tier,
+        2,
+        expiry,
+        1,
+    );
+    let other_minter_signature = signer.sign(&other_minter_message).to_bytes();
+    nm_setup
+        .call_buy_random_nft_with_signature(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+            expiry,
+            1,
+            &other_minter_signature,
+        )
+        .assert_error(10, "ed25519 verify error");
+
+    nm_setup
+        .call_buy_random_nft_with_signature(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+            expiry,
+            1,
+            &signature,
+        )
+        .assert_ok();
+
+    // try replay
+    nm_setup
+        .call_buy_random_nft_with_signature(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT * 2,
+            FIRST_BRAND_ID,
+            first_tier,
+            2,
+            expiry,
+            1,
+            &signature,
+        )
+        .assert_user_error("Mint signature already used");
+
+    // try use after expiry
+    let message = nm_setup.build_mint_signature_message(
+        &minter_addr,
+        &first_user_addr,
+        FIRST_BRAND_ID,
+        first_tier,
+        1,
+        expiry,
+        2,
+    );
+    let signature = signer.sign(&message).to_bytes();
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(expiry));
+    nm_setup
+        .call_buy_random_nft_with_signature(
+            &first_user_addr,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            first_tier,
+            1,
+            expiry,
+            2,
+            &signature,
+        )
+        .assert_user_error("Mint signature expired");
+}
+
+#[test]
+fn giveaway_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+
+    let first_tier = SECOND_TIERS[0];
+
+    // giveaway single nft
+    let first_user_addr = nm_setup.first_user_address.clone();
+    nm_setup
+        .call_giveaway(
+            SECOND_BRAND_ID,
+            first_tier,
+            [(first_user_addr.clone(), 1)].to_vec(),
+        )
+        .assert_ok();
+
+    // user received nonce 1 and ID 7
+    let mut attr = nm_setup.build_nft_attributes_second_token(7);
+    nm_setup.b_mock.check_nft_balance(
+        &first_user_addr,
+        SECOND_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        Some(&attr),
+    );
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let mapper = sc.available_ids(
+                &managed_buffer!(SECOND_BRAND_ID),
+                &managed_buffer!(first_tier),
+            );
+            assert_eq!(mapper.len(), 9);
+            assert_eq!(mapper.get(1), 1);
+            assert_eq!(mapper.get(2), 2);
+            assert_eq!(mapper.get(3), 3);
+            assert_eq!(mapper.get(4), 4);
+            assert_eq!(mapper.get(5), 5);
+            assert_eq!(mapper.get(6), 6);
+            assert_eq!(mapper.get(7), 10); // this changed
+            assert_eq!(mapper.get(8), 8);
+            assert_eq!(mapper.get(9), 9);
+        })
+        .assert_ok();
+
+    // giveaway two, single user
+    nm_setup
+        .call_giveaway(
+            SECOND_BRAND_ID,
+            first_tier,
+            [(first_user_addr.clone(), 2)].to_vec(),
+        )
+        .assert_ok();
+
+    // received ID 4 and 5
+    attr = nm_setup.build_nft_attributes_second_token(4);
+    nm_setup.b_mock.check_nft_balance(
+        &first_user_addr,
+        SECOND_TOKEN_ID,
+        2,
+        &rust_biguint!(1),
+        Some(&attr),
+    );
+
+    attr = nm_setup.build_nft_attributes_second_token(5);
+    nm_setup.b_mock.check_nft_balance(
+        &first_user_addr,
+        SECOND_TOKEN_ID,
+        3,
+        &rust_biguint!(1),
+        Some(&attr),
+    );
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let mapper = sc.available_ids(
+                &managed_buffer!(SECOND_BRAND_ID),
+                &managed_buffer!(first_tier),
+            );
+            assert_eq!(mapper.len(), 7);
+            assert_eq!(mapper.get(1), 1);
+            assert_eq!(mapper.get(2), 2);
+            assert_eq!(mapper.get(3), 3);
+            assert_eq!(mapper.get(4), 9); // pos 4
+            assert_eq!(mapper.get(5), 8); // and 5 changed
+            assert_eq!(mapper.get(6), 6);
+            assert_eq!(mapper.get(7), 10);
+        })
+        .assert_ok();
+
+    // giveaway, multiple users
+    let second_user_addr = nm_setup.second_user_address.clone();
+    nm_setup
+        .call_giveaway(
+            SECOND_BRAND_ID,
+            first_tier,
+            [(first_user_addr.clone(), 2), (second_user_addr.clone(), 1)].to_vec(),
+        )
+        .assert_ok();
+
+    // user 1 received IDs 10 and 1
+    attr = nm_setup.build_nft_attributes_second_token(10);
+    nm_setup.b_mock.check_nft_balance(
+        &first_user_addr,
+        SECOND_TOKEN_ID,
+        4,
+        &rust_biguint!(1),
+        Some(&attr),
+    );
+
+    attr = nm_setup.build_nft_attributes_second_token(1);
+    nm_setup.b_mock.check_nft_balance(
+        &first_user_addr,
+        SECOND_TOKEN_ID,
+        5,
+        &rust_biguint!(1),
+        Some(&attr),
+    );
+
+    // user 2 received ID
+    attr = nm_setup.build_nft_attributes_second_token(6);
+    nm_setup.b_mock.check_nft_balance(
+        &second_user_addr,
+        SECOND_TOKEN_ID,
+        6,
+        &rust_biguint!(1),
+        Some(&attr),
+    );
+
+    // mapper progress:
+    // 1 2 3 9 8 6 10
+    // 1 2 3 9 8 6
+    // 6 2 3 9 8
+    // 8 2 3 9
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let mapper = sc.available_ids(
+                &managed_buffer!(SECOND_BRAND_ID),
+                &managed_buffer!(first_tier),
+            );
+            assert_eq!(mapper.len(), 4);
+            assert_eq!(mapper.get(1), 8);
+            assert_eq!(mapper.get(2), 2);
+            assert_eq!(mapper.get(3), 3);
+            assert_eq!(mapper.get(4), 9);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn formatters_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+
+    // test NFT attributes
     nm_setup
         .b_mock
         .execute_query(&nm_setup.nm_wrapper, |sc| {
@@ -583,9 +3548,10 @@ fn formatters_test() {
     nm_setup
         .b_mock
         .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let uri_base = managed_buffer!(FIRST_URI_BASE);
             let coll_hash = ManagedByteArray::new_from_bytes(FIRST_COLLECTION_HASH);
             let nft_main_file_uri =
-                sc.build_nft_main_file_uri(&coll_hash, 2, &managed_buffer!(b"jpg"));
+                sc.build_nft_main_file_uri(&uri_base, &coll_hash, 2, &managed_buffer!(b"jpg"));
             let expected_main_file_uri =
                 "https://ipfs.io/ipfs/FirstCollection_______________________________/2.jpg";
             assert_eq!(
@@ -593,7 +3559,7 @@ fn formatters_test() {
                 expected_main_file_uri.to_string()
             );
 
-            let nft_json_file_uri = sc.build_nft_json_file_uri(&coll_hash, 2);
+            let nft_json_file_uri = sc.build_nft_json_file_uri(&uri_base, &coll_hash, 2);
             let expected_nft_json_uri =
                 "https://ipfs.io/ipfs/FirstCollection_______________________________/2.json";
             assert_eq!(
@@ -601,7 +3567,7 @@ fn formatters_test() {
                 expected_nft_json_uri.to_string()
             );
 
-            let collection_json_uri = sc.build_collection_json_file_uri(&coll_hash);
+            let collection_json_uri = sc.build_collection_json_file_uri(&uri_base, &coll_hash);
             let expected_collection_json_uri = "https://ipfs.io/ipfs/FirstCollection_______________________________/collection.json";
             assert_eq!(
                 managed_buffer_to_string(&collection_json_uri),
@@ -609,6 +3575,263 @@ fn formatters_test() {
             );
         })
         .assert_ok();
+
+    // test generated URIs, raw IPFS scheme
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let uri_base = managed_buffer!(SECOND_URI_BASE);
+            let coll_hash = ManagedByteArray::new_from_bytes(SECOND_COLLECTION_HASH);
+            let nft_main_file_uri =
+                sc.build_nft_main_file_uri(&uri_base, &coll_hash, 3, &managed_buffer!(b"mp3"));
+            let expected_main_file_uri =
+                "ipfs://SecondCollection______________________________/3.mp3";
+            assert_eq!(
+                managed_buffer_to_string(&nft_main_file_uri),
+                expected_main_file_uri.to_string()
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_uri_base_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+
+    let owner_addr = nm_setup.owner_address.clone();
+    let first_user_addr = nm_setup.first_user_address.clone();
+    let new_uri_base = b"https://arweave.net/";
+
+    // user may not change URI base
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &first_user_addr,
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_uri_base_for_brand(
+                    managed_buffer!(FIRST_BRAND_ID),
+                    managed_buffer!(new_uri_base),
+                );
+            },
+        )
+        .assert_user_error("Item not whitelisted");
+
+    // change URI base before minting ok
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_uri_base_for_brand(
+                managed_buffer!(FIRST_BRAND_ID),
+                managed_buffer!(new_uri_base),
+            );
+        })
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            let result = sc.get_brand_info_view(managed_buffer!(FIRST_BRAND_ID));
+            assert_eq!(result.brand_info.uri_base, managed_buffer!(new_uri_base));
+        })
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    nm_setup
+        .call_buy_random_nft(
+            &first_user_addr,
+            FIRST_MINT_PRICE_TOKEN_ID,
+            FIRST_MINT_PRICE_AMOUNT,
+            FIRST_BRAND_ID,
+            FIRST_TIERS[0],
+            1,
+        )
+        .assert_ok();
+
+    // try change URI base after minting
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_uri_base_for_brand(
+                managed_buffer!(FIRST_BRAND_ID),
+                managed_buffer!(FIRST_URI_BASE),
+            );
+        })
+        .assert_user_error("May not change URI base after minting started");
+}
+
+#[test]
+fn storage_migration_test() {
+    let mut nm_setup = NftMinterSetup::new(nft_minter::contract_obj);
+    nm_setup.create_default_brands();
+
+    let owner_addr = nm_setup.owner_address.clone();
+    let first_user_addr = nm_setup.first_user_address.clone();
+
+    // simulate a contract deployed before the storage was versioned
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.legacy_brand_info(&managed_buffer!(FIRST_BRAND_ID))
+                .set(LegacyBrandInfo {
+                    collection_hash: ManagedByteArray::new_from_bytes(FIRST_COLLECTION_HASH),
+                    token_display_name: managed_buffer!(FIRST_TOKEN_DISPLAY_NAME),
+                    media_type: managed_buffer!(FIRST_MEDIA_TYPE),
+                    royalties: managed_biguint!(0),
+                    mint_period: TimePeriod {
+                        start: FIRST_MINT_START_TIMESTAMP,
+                        end: FIRST_MINT_END_TIMESTAMP,
+                    },
+                    whitelist_expire_timestamp: FIRST_MINT_START_TIMESTAMP + 1,
+                });
+            // whitelisted before quotas existed
+            let _ = sc
+                .mint_whitelist(&managed_buffer!(FIRST_BRAND_ID))
+                .insert(managed_address!(&first_user_addr));
+            let mut prices_mapper = sc.prices_for_tier(
+                &managed_buffer!(FIRST_BRAND_ID),
+                &managed_buffer!(FIRST_TIERS[0]),
+            );
+            let _ = prices_mapper.remove(&managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID));
+            sc.legacy_price_for_tier(
+                &managed_buffer!(FIRST_BRAND_ID),
+                &managed_buffer!(FIRST_TIERS[0]),
+            )
+            .set(MintPrice {
+                token_id: managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID),
+                amount: managed_biguint!(FIRST_MINT_PRICE_AMOUNT),
+            });
+            sc.storage_version().clear();
+
+            let _ = sc.legacy_accumulated_mint_payments().insert(
+                managed_token_id!(EGLD_TOKEN_ID),
+                managed_biguint!(FIRST_MINT_PRICE_AMOUNT),
+            );
+            let _ = sc.legacy_accumulated_royalties().insert(
+                managed_token_id!(EGLD_TOKEN_ID),
+                managed_biguint!(SECOND_MINT_PRICE_AMOUNT),
+            );
+        })
+        .assert_ok();
+    nm_setup.b_mock.set_egld_balance(
+        nm_setup.nm_wrapper.address_ref(),
+        &rust_biguint!(FIRST_MINT_PRICE_AMOUNT + SECOND_MINT_PRICE_AMOUNT),
+    );
+
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            sc.upgrade(
+                managed_address!(&owner_addr),
+                managed_address!(&owner_addr),
+                MAX_NFTS_PER_TX,
+                managed_address!(&owner_addr),
+                0,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            assert_eq!(sc.storage_version().get(), CURRENT_STORAGE_VERSION);
+
+            let result = sc.get_brand_info_view(managed_buffer!(FIRST_BRAND_ID));
+            assert_eq!(
+                result.brand_info.uri_base,
+                managed_buffer!(b"https://ipfs.io/ipfs/")
+            );
+            assert_eq!(
+                result.brand_info.mint_period,
+                TimePeriod {
+                    start: FIRST_MINT_START_TIMESTAMP,
+                    end: FIRST_MINT_END_TIMESTAMP,
+                }
+            );
+
+            // brands created with the current layout are left as they are
+            let result = sc.get_brand_info_view(managed_buffer!(SECOND_BRAND_ID));
+            assert_eq!(result.brand_info.uri_base, managed_buffer!(SECOND_URI_BASE));
+
+            let prices_mapper = sc.prices_for_tier(
+                &managed_buffer!(FIRST_BRAND_ID),
+                &managed_buffer!(FIRST_TIERS[0]),
+            );
+            assert_eq!(
+                prices_mapper.get(&managed_token_id!(FIRST_MINT_PRICE_TOKEN_ID)),
+                Some(managed_biguint!(FIRST_MINT_PRICE_AMOUNT))
+            );
+            assert!(sc
+                .legacy_price_for_tier(
+                    &managed_buffer!(FIRST_BRAND_ID),
+                    &managed_buffer!(FIRST_TIERS[0]),
+                )
+                .is_empty());
+        })
+        .assert_ok();
+
+    // legacy balances are claimed by the contract-wide claim addresses
+    nm_setup
+        .b_mock
+        .execute_tx(
+            &nm_setup.first_user_address.clone(),
+            &nm_setup.nm_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_legacy_mint_payments();
+            },
+        )
+        .assert_user_error("Claim not allowed");
+
+    let owner_balance_before = nm_setup.b_mock.get_egld_balance(&owner_addr);
+    nm_setup
+        .b_mock
+        .execute_tx(&owner_addr, &nm_setup.nm_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.claim_legacy_mint_payments();
+            let _ = sc.claim_legacy_royalties();
+            assert!(sc.legacy_accumulated_mint_payments().is_empty());
+            assert!(sc.legacy_accumulated_royalties().is_empty());
+        })
+        .assert_ok();
+    nm_setup.b_mock.check_egld_balance(
+        &owner_addr,
+        &(owner_balance_before + FIRST_MINT_PRICE_AMOUNT + SECOND_MINT_PRICE_AMOUNT),
+    );
+
+    // migrated prices are accepted, and legacy whitelist entries keep their unlimited access
+    nm_setup
+        .b_mock
+        .set_block_timestamp_seconds(TimestampSeconds::new(FIRST_MINT_START_TIMESTAMP));
+    for _ in 0..2 {
+        nm_setup
+            .call_buy_random_nft(
+                &first_user_addr,
+                FIRST_MINT_PRICE_TOKEN_ID,
+                FIRST_MINT_PRICE_AMOUNT,
+                FIRST_BRAND_ID,
+                FIRST_TIERS[0],
+                1,
+            )
+            .assert_ok();
+    }
+    nm_setup
+        .b_mock
+        .execute_query(&nm_setup.nm_wrapper, |sc| {
+            assert_eq!(
+                sc.whitelist_quota(
+                    &managed_buffer!(FIRST_BRAND_ID),
+                    &managed_address!(&first_user_addr)
+                )
+                .get(),
+                LEGACY_WHITELIST_QUOTA - 2
+            );
+        })
+        .assert_ok();
 }
 
 fn managed_buffer_to_string(buffer: &ManagedBuffer<DebugApi>) -> String {
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           33
+// Endpoints:                          128
 // Async Callback:                       1
-// Total number of exported functions:  36
+// Total number of exported functions: 131
 
 #![no_std]
 
@@ -25,29 +25,124 @@ multiversx_sc_wasm_adapter::endpoints! {
         getRegisterdCollectionHashes => registered_collection_hashes
         getRegisteredBrands => registered_brands
         getNftTokenIdForBrand => nft_token
-        getPriceForTier => price_for_tier
+        getPricesForTier => prices_for_tier
         getTagsForBrand => tags_for_brand
         getMintWhitelist => mint_whitelist
+        getWhitelistQuota => whitelist_quota
+        isBrandCancelled => is_brand_cancelled
         addUserToAdminList => add_user_to_admin_list
         removeUserFromAdminList => remove_user_from_admin_list
         issueTokenForBrand => issue_token_for_brand
         addToWhitelist => add_to_whitelist
         removeFromWhitelist => remove_from_whitelist
         setMintWhitelistExpireTimestamp => set_mint_whitelist_expire_timestamp
+        setMintPeriod => set_mint_period
+        setUriBaseForBrand => set_uri_base_for_brand
         buyRandomNft => buy_random_nft
+        buyRandomNftWithReferrer => buy_random_nft_with_referrer
         giveawayNfts => giveaway_nfts
+        commitBuy => commit_buy
+        revealBuy => reveal_buy
+        refundExpiredBuy => refund_expired_buy
+        getPendingBuy => pending_buy
+        addRedemptionProgram => add_redemption_program
+        setRedemptionPeriod => set_redemption_period
+        setMaxRedemptions => set_max_redemptions
+        redeem => redeem
+        getRedemptionPrograms => redemption_programs
+        getRedemptionsCount => redemptions_count
+        addCraftingRecipe => add_crafting_recipe
+        craft => craft
+        getCraftingRecipes => crafting_recipes
+        enableDelayedReveal => enable_delayed_reveal
+        scheduleReveal => schedule_reveal
+        reveal => reveal
+        revealNfts => reveal_nfts
+        getPlaceholderUri => placeholder_uri
+        getRevealBlockNonce => reveal_block_nonce
+        isRevealed => is_revealed
+        getRevealOffset => reveal_offset
+        getPlaceholderInfo => placeholder_info
+        setMaxNftsPerAddress => set_max_nfts_per_address
+        setMaxNftsPerAddressForTier => set_max_nfts_per_address_for_tier
+        getMaxNftsPerAddress => max_nfts_per_address
+        getMaxNftsPerAddressForTier => max_nfts_per_address_for_tier
+        getMintedCountForAddress => minted_count_for_address
+        getMintedCountForAddressAndTier => minted_count_for_address_and_tier
+        addMintPhase => add_mint_phase
+        clearMintPhases => clear_mint_phases
+        addToPhaseEligibilityList => add_to_phase_eligibility_list
+        removeFromPhaseEligibilityList => remove_from_phase_eligibility_list
+        getActivePhase => get_active_phase_view
+        getMintedCountForPhase => get_minted_count_for_phase
+        getMintPhases => mint_phases
+        getPhaseEligibilityList => phase_eligibility_list
+        setWhitelistMerkleRoot => set_whitelist_merkle_root
+        clearWhitelistMerkleRoot => clear_whitelist_merkle_root
+        getWhitelistMerkleRoot => whitelist_merkle_root
+        getMerkleWhitelistMintedCount => merkle_whitelist_minted_count
+        setMintSigner => set_mint_signer
+        clearMintSigner => clear_mint_signer
+        getMintSigner => mint_signer
         getNftTiersForBrand => nft_tiers_for_brand
         nftIdOffsetForTier => nft_id_offset_for_tier
+        isEditionBrand => is_edition_brand
+        isDynamicBrand => is_dynamic_brand
+        getEditionNonce => edition_nonce
+        getTierForNft => tier_for_nft
+        getReservedNfts => reserved_nfts
+        getCommittedNfts => committed_nfts
+        updateNftAttributes => update_nft_attributes
+        updateNftUris => update_nft_uris
+        addPriceForTier => add_price_for_tier
+        setPriceForTier => set_price_for_tier
+        removePriceForTier => remove_price_for_tier
+        setDutchAuctionForTier => set_dutch_auction_for_tier
+        removeDutchAuctionForTier => remove_dutch_auction_for_tier
+        setPresalePriceForTier => set_presale_price_for_tier
+        removePresalePriceForTier => remove_presale_price_for_tier
+        lockPricesAfterMintStart => lock_prices_after_mint_start
+        getCurrentPriceForTier => get_current_price_for_tier
+        getPresalePriceForTier => presale_price_for_tier
+        getDutchAuctionForTier => dutch_auction_for_tier
+        getPriceCurveForTier => price_curve_for_tier
+        arePricesLockedAfterMintStart => prices_locked_after_mint_start
         setRoyaltiesClaimAddress => set_royalties_claim_address
         changeRoyaltiesForBrand => change_royalties_for_brand
         setMintPaymentsClaimAddress => set_mint_payments_claim_address
+        setMintPaymentsClaimAddressForBrand => set_mint_payments_claim_address_for_brand
+        setRoyaltiesClaimAddressForBrand => set_royalties_claim_address_for_brand
+        setMintPaymentsSplit => set_mint_payments_split
+        clearMintPaymentsSplit => clear_mint_payments_split
         claimRoyalties => claim_royalties
         claimMintPayments => claim_mint_payments
+        getAccumulatedMintPaymentsPerBrand => get_accumulated_mint_payments_per_brand
+        getAccumulatedRoyaltiesPerBrand => get_accumulated_royalties_per_brand
         getRoyaltiesClaimAddress => royalties_claim_address
         getMintPaymentsClaimAddress => mint_payments_claim_address
+        getRoyaltiesClaimAddressForBrand => royalties_claim_address_for_brand
+        getMintPaymentsClaimAddressForBrand => mint_payments_claim_address_for_brand
+        getMintPaymentsSplit => mint_payments_split
         getAccumulatedRoyalties => accumulated_royalties
         getAccumulatedMintPayments => accumulated_mint_payments
+        claimPlatformFees => claim_platform_fees
+        getPlatformFeeAddress => platform_fee_address
+        getPlatformFee => platform_fee
+        getAccumulatedPlatformFees => accumulated_platform_fees
+        cancelBrand => cancel_brand
+        refundNft => refund_nft
+        getPaidPriceForNft => paid_price_for_nft
+        getEditionPurchases => edition_purchases
+        setReferralShare => set_referral_share
+        claimReferralRewards => claim_referral_rewards
+        getReferralShare => referral_share
+        getReferralRewards => referral_rewards
         claimRoyaltiesFromMarketplace => claim_royalties_from_marketplace
+        claimLegacyRoyalties => claim_legacy_royalties
+        claimLegacyMintPayments => claim_legacy_mint_payments
+        getStorageVersion => storage_version
+        getLegacyAccumulatedRoyalties => legacy_accumulated_royalties
+        getLegacyAccumulatedMintPayments => legacy_accumulated_mint_payments
         getBrandInfo => get_brand_info_view
         getAllBrandsInfo => get_all_brands_info
         pause => pause_endpoint
@@ -1,14 +1,19 @@
 multiversx_sc::imports!();
 
-use nft_minter::common_storage::EgldValuePaymentsVecPair;
+use nft_minter::common_storage::{BrandId, EgldValuePaymentsVecPair};
 
 #[multiversx_sc::module]
 pub trait NftMinterInteractorModule:
     crate::common_storage::CommonStorageModule + crate::token_balance::TokenBalanceModule
 {
+    /// Royalties and mint payments are claimed for each of the given brands,
+    /// along with the ones accumulated before they were tracked per brand
     #[only_owner]
     #[endpoint(claimNftMinterPaymentsAndRoyalties)]
-    fn claim_nft_minter_payments_and_royalties(&self) {
+    fn claim_nft_minter_payments_and_royalties(
+        &self,
+        brand_ids: MultiValueEncoded<BrandId<Self::Api>>,
+    ) {
         let current_epoch = self.blockchain().get_block_epoch();
         let last_claim_epoch = self.last_claim_epoch().get();
         require!(
@@ -20,21 +25,30 @@ pub trait NftMinterInteractorModule:
 
         let sc_address = self.nft_minter_sc_address().get();
 
-        let royalties_result = self.call_claim_royalties(sc_address.clone());
-        self.update_balance_from_results(royalties_result);
+        let legacy_royalties_result = self.call_claim_legacy_royalties(sc_address.clone());
+        self.update_balance_from_results(legacy_royalties_result);
+
+        let legacy_mint_payments_result = self.call_claim_legacy_mint_payments(sc_address.clone());
+        self.update_balance_from_results(legacy_mint_payments_result);
+
+        for brand_id in brand_ids {
+            let royalties_result = self.call_claim_royalties(sc_address.clone(), brand_id.clone());
+            self.update_balance_from_results(royalties_result);
 
-        let mint_payments_result = self.call_claim_mint_payments(sc_address);
-        self.update_balance_from_results(mint_payments_result);
+            let mint_payments_result = self.call_claim_mint_payments(sc_address.clone(), brand_id);
+            self.update_balance_from_results(mint_payments_result);
+        }
     }
 
     fn call_claim_royalties(
         &self,
         sc_address: ManagedAddress,
+        brand_id: BrandId<Self::Api>,
     ) -> EgldValuePaymentsVecPair<Self::Api> {
         self.tx()
             .to(sc_address)
             .typed(nft_minter::nft_minter_proxy::NftMinterProxy)
-            .claim_royalties()
+            .claim_royalties(brand_id)
             .returns(ReturnsResult)
             .sync_call()
     }
@@ -42,11 +56,36 @@ pub trait NftMinterInteractorModule:
     fn call_claim_mint_payments(
         &self,
         sc_address: ManagedAddress,
+        brand_id: BrandId<Self::Api>,
+    ) -> EgldValuePaymentsVecPair<Self::Api> {
+        self.tx()
+            .to(sc_address)
+            .typed(nft_minter::nft_minter_proxy::NftMinterProxy)
+            .claim_mint_payments(brand_id)
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    fn call_claim_legacy_royalties(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> EgldValuePaymentsVecPair<Self::Api> {
+        self.tx()
+            .to(sc_address)
+            .typed(nft_minter::nft_minter_proxy::NftMinterProxy)
+            .claim_legacy_royalties()
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    fn call_claim_legacy_mint_payments(
+        &self,
+        sc_address: ManagedAddress,
     ) -> EgldValuePaymentsVecPair<Self::Api> {
         self.tx()
             .to(sc_address)
             .typed(nft_minter::nft_minter_proxy::NftMinterProxy)
-            .claim_mint_payments()
+            .claim_legacy_mint_payments()
             .returns(ReturnsResult)
             .sync_call()
     }
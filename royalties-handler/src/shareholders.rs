@@ -100,8 +100,8 @@ pub trait ShareholdersModule:
         for p in &reward_entry.esdt_payments {
             result.push(
                 (
-                    EgldOrEsdtTokenIdentifier::esdt(p.token_identifier),
-                    p.amount,
+                    EgldOrEsdtTokenIdentifier::esdt(p.token_identifier.clone()),
+                    p.amount.clone(),
                 )
                     .into(),
             );
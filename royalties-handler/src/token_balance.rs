@@ -19,7 +19,7 @@ pub trait TokenBalanceModule {
         }
         for p in &other_payments {
             self.add_balance(
-                EgldOrEsdtTokenIdentifier::esdt(p.token_identifier),
+                EgldOrEsdtTokenIdentifier::esdt(p.token_identifier.clone()),
                 &p.amount,
             );
         }
@@ -1,8 +1,7 @@
 use nft_minter::nft_attributes_builder::COLLECTION_HASH_LEN;
 
-pub const ESDT_SYSTEM_SC_ADDRESS_ARRAY: [u8; 32] = multiversx_sc::hex_literal::hex!(
-    "000000000000000000010000000000000000000000000000000000000002ffff"
-);
+pub const ESDT_SYSTEM_SC_ADDRESS_ARRAY: [u8; 32] =
+    hex_literal::hex!("000000000000000000010000000000000000000000000000000000000002ffff");
 pub const ISSUE_COST: u64 = 50_000_000_000_000_000;
 pub const OWNER_EGLD_BALANCE: u64 = 150_000_000_000_000_000;
 pub const USER_EGLD_BALANCE: u64 = 1_000_000_000;
@@ -13,6 +12,7 @@ pub const FIRST_COLLECTION_HASH: &[u8; COLLECTION_HASH_LEN] =
     b"FirstCollection_______________________________";
 pub const FIRST_BRAND_ID: &[u8] = b"FirstBrand";
 pub const FIRST_MEDIA_TYPE: &[u8] = b"png";
+pub const FIRST_URI_BASE: &[u8] = b"https://ipfs.io/ipfs/";
 pub const FIRST_MINT_START_TIMESTAMP: u64 = 100_000_000;
 pub const FIRST_MINT_END_TIMESTAMP: u64 = 200_000_000;
 pub const FIRST_MINT_PRICE_TOKEN_ID: &[u8] = EGLD_TOKEN_ID;
@@ -28,6 +28,7 @@ pub const SECOND_COLLECTION_HASH: &[u8; COLLECTION_HASH_LEN] =
     b"SecondCollection______________________________";
 pub const SECOND_BRAND_ID: &[u8] = b"SecondBrand";
 pub const SECOND_MEDIA_TYPE: &[u8] = b"mp3";
+pub const SECOND_URI_BASE: &[u8] = b"https://ipfs.io/ipfs/";
 pub const SECOND_MINT_START_TIMESTAMP: u64 = 200_000_000;
 pub const SECOND_MINT_END_TIMESTAMP: u64 = u64::MAX;
 pub const SECOND_MINT_PRICE_TOKEN_ID: &[u8] = EGLD_TOKEN_ID;
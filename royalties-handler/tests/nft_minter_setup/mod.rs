@@ -7,7 +7,7 @@ use multiversx_sc_scenario::{
     imports::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
     managed_address, managed_biguint, managed_buffer, rust_biguint, DebugApi,
 };
-use nft_minter::brand_creation::BrandCreationModule;
+use nft_minter::brand_creation::{BrandCreationModule, BrandTokenKind};
 use nft_minter::nft_attributes_builder::COLLECTION_HASH_LEN;
 use nft_minter::royalties::RoyaltiesModule;
 use nft_minter::NftMinter;
@@ -56,6 +56,8 @@ where
                     managed_address!(&owner_address),
                     managed_address!(&owner_address),
                     1_000,
+                    managed_address!(&owner_address),
+                    0,
                     OptionalValue::None,
                 );
             })
@@ -64,10 +66,11 @@ where
         // simulate royalties balance
         b_mock
             .execute_tx(&owner_address, &nm_wrapper, &rust_zero, |sc| {
-                sc.accumulated_royalties().insert(
-                    managed_token_id!(ROYALTIES_TOKEN_ID),
-                    managed_biguint!(ROYALTIES_TOKEN_BALANCE),
-                );
+                sc.accumulated_royalties(&managed_buffer!(FIRST_BRAND_ID))
+                    .insert(
+                        managed_token_id!(ROYALTIES_TOKEN_ID),
+                        managed_biguint!(ROYALTIES_TOKEN_BALANCE),
+                    );
             })
             .assert_ok();
 
@@ -80,10 +83,11 @@ where
         // simulate mint payments balance
         b_mock
             .execute_tx(&owner_address, &nm_wrapper, &rust_zero, |sc| {
-                sc.accumulated_mint_payments().insert(
-                    managed_token_id!(EGLD_TOKEN_ID),
-                    managed_biguint!(MINT_PAYMENTS_BALANCE),
-                );
+                sc.accumulated_mint_payments(&managed_buffer!(FIRST_BRAND_ID))
+                    .insert(
+                        managed_token_id!(EGLD_TOKEN_ID),
+                        managed_biguint!(MINT_PAYMENTS_BALANCE),
+                    );
             })
             .assert_ok();
 
@@ -106,6 +110,7 @@ where
             FIRST_COLLECTION_HASH,
             FIRST_BRAND_ID,
             FIRST_MEDIA_TYPE,
+            FIRST_URI_BASE,
             0,
             FIRST_MINT_START_TIMESTAMP,
             FIRST_MINT_END_TIMESTAMP,
@@ -123,6 +128,7 @@ where
             SECOND_COLLECTION_HASH,
             SECOND_BRAND_ID,
             SECOND_MEDIA_TYPE,
+            SECOND_URI_BASE,
             0,
             SECOND_MINT_START_TIMESTAMP,
             SECOND_MINT_END_TIMESTAMP,
@@ -153,11 +159,13 @@ impl<NftMinterObjBuilder> NftMinterSetup<NftMinterObjBuilder>
 where
     NftMinterObjBuilder: 'static + Copy + Fn() -> nft_minter::ContractObj<DebugApi>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn call_create_new_brand(
         &mut self,
         collection_hash: &[u8; COLLECTION_HASH_LEN],
         brand_id: &[u8],
         media_type: &[u8],
+        uri_base: &[u8],
         royalties: u64,
         mint_start_timestamp: u64,
         mint_end_timestamp: u64,
@@ -190,6 +198,9 @@ where
                             managed_buffer!(tier),
                             *nr_nfts,
                             managed_biguint!(mint_price_amount),
+                            None,
+                            None,
+                            0,
                         )
                             .into(),
                     );
@@ -199,6 +210,7 @@ where
                     collection_hash.into(),
                     managed_buffer!(brand_id),
                     managed_buffer!(media_type),
+                    managed_buffer!(uri_base),
                     managed_biguint!(royalties),
                     mint_start_timestamp,
                     mint_end_timestamp,
@@ -207,6 +219,7 @@ where
                     managed_buffer!(token_ticker),
                     0,
                     managed_tags,
+                    BrandTokenKind::Nft,
                     tier_args,
                 );
             },
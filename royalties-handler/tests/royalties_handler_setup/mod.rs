@@ -1,9 +1,10 @@
+use crate::constants::{FIRST_BRAND_ID, SECOND_BRAND_ID};
 use crate::nft_minter_setup::NftMinterSetup;
 use multiversx_sc::types::{Address, MultiValueEncoded};
 use multiversx_sc_scenario::{
     imports::TxResult,
     imports::{BlockchainStateWrapper, ContractObjWrapper},
-    managed_address, rust_biguint, DebugApi,
+    managed_address, managed_buffer, rust_biguint, DebugApi,
 };
 use nft_minter::royalties::RoyaltiesModule;
 use royalties_handler::nft_minter_interactor::NftMinterInteractorModule;
@@ -88,7 +89,11 @@ where
             &self.rh_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.claim_nft_minter_payments_and_royalties();
+                let mut brand_ids = MultiValueEncoded::new();
+                brand_ids.push(managed_buffer!(FIRST_BRAND_ID));
+                brand_ids.push(managed_buffer!(SECOND_BRAND_ID));
+
+                sc.claim_nft_minter_payments_and_royalties(brand_ids);
             },
         )
     }
@@ -106,7 +111,7 @@ where
 
     pub fn call_claim_rewards(&mut self, caller: &Address, entry_ids: &[usize]) -> TxResult {
         self.b_mock
-            .execute_tx(&caller, &self.rh_wrapper, &rust_biguint!(0), |sc| {
+            .execute_tx(caller, &self.rh_wrapper, &rust_biguint!(0), |sc| {
                 let mut args = MultiValueEncoded::new();
                 for id in entry_ids {
                     args.push(*id);